@@ -0,0 +1,175 @@
+//! Closures and the `Fn` trait hierarchy, shown through small combinators.
+//!
+//! - `FnOnce`: can be called once, since calling it may consume captured values.
+//! - `FnMut`: can be called repeatedly, but needs `&mut` access to its captured state.
+//! - `Fn`: can be called through a shared reference, so it can't mutate captured state
+//!   directly; interior mutability (`Cell`, `RefCell`) is the way around that.
+//!
+//! Every `Fn` is also `FnMut`, and every `FnMut` is also `FnOnce`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Wraps `f` so that each distinct argument is only ever computed once.
+///
+/// The returned closure captures a cache of past results. The cache lives in a `RefCell`,
+/// so the closure only needs shared access to itself and is `Fn` rather than just `FnMut`.
+///
+/// # Example
+///
+/// ```rust
+/// use functions::memoize;
+///
+/// let square = memoize(|x: u64| x * x);
+/// assert_eq!(square(4), 16);
+/// assert_eq!(square(4), 16); // served from the cache
+/// ```
+pub fn memoize<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    A: Eq + Hash + Clone,
+    R: Clone,
+    F: Fn(A) -> R,
+{
+    let cache = RefCell::new(HashMap::<A, R>::new());
+    move |arg: A| {
+        if let Some(result) = cache.borrow().get(&arg) {
+            return result.clone();
+        }
+        // The shared borrow above has ended, so `f` may even call back into this closure.
+        let result = f(arg.clone());
+        cache.borrow_mut().insert(arg, result.clone());
+        result
+    }
+}
+
+/// Calls `f` up to `attempts` times, returning the first `Ok` or else the last `Err`.
+///
+/// `f` is `FnMut` because a retried operation usually carries state between attempts
+/// (a counter, a backoff, a connection to re-open). `f` is always called at least once,
+/// even if `attempts` is `0`, since there is no other way to produce an `Err`.
+///
+/// # Example
+///
+/// ```rust
+/// use functions::retry;
+///
+/// let mut failures_left = 2;
+/// let result = retry(3, || {
+///     if failures_left > 0 {
+///         failures_left -= 1;
+///         Err("not yet")
+///     } else {
+///         Ok("done")
+///     }
+/// });
+/// assert_eq!(result, Ok("done"));
+/// ```
+pub fn retry<T, E, F>(attempts: usize, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    for _ in 1..attempts {
+        if let Ok(value) = f() {
+            return Ok(value);
+        }
+    }
+    f()
+}
+
+/// Chains two closures into one that applies `f` and then `g`.
+///
+/// Both closures are moved into the returned one; since they are only ever called
+/// through a shared reference, `Fn` is all that is needed.
+///
+/// # Example
+///
+/// ```rust
+/// use functions::compose;
+///
+/// let add_one_then_double = compose(|x: i32| x + 1, |x: i32| x * 2);
+/// assert_eq!(add_one_then_double(3), 8);
+/// ```
+pub fn compose<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(A) -> B,
+    G: Fn(B) -> C,
+{
+    move |x| g(f(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn memoize_runs_once_per_argument() {
+        let calls = Cell::new(0);
+        let double = memoize(|x: i32| {
+            calls.set(calls.get() + 1);
+            x * 2
+        });
+
+        assert_eq!(double(1), 2);
+        assert_eq!(double(1), 2);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(double(2), 4);
+        assert_eq!(double(1), 2);
+        assert_eq!(double(2), 4);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn memoize_owned_arguments() {
+        let calls = Cell::new(0);
+        let len = memoize(|s: String| {
+            calls.set(calls.get() + 1);
+            s.len()
+        });
+
+        assert_eq!(len("hello".to_string()), 5);
+        assert_eq!(len("hello".to_string()), 5);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn retry_succeeds_after_failures() {
+        let attempts = Cell::new(0);
+        let result: Result<&str, &str> = retry(5, || {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() <= 2 {
+                Err("flaky")
+            } else {
+                Ok("done")
+            }
+        });
+
+        assert_eq!(result, Ok("done"));
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn retry_returns_last_error() {
+        let attempts = Cell::new(0);
+        let result: Result<(), usize> = retry(4, || {
+            attempts.set(attempts.get() + 1);
+            Err(attempts.get())
+        });
+
+        assert_eq!(result, Err(4));
+        assert_eq!(attempts.get(), 4);
+    }
+
+    #[test]
+    fn retry_zero_attempts_calls_once() {
+        let attempts = Cell::new(0);
+        let result: Result<(), ()> = retry(0, || {
+            attempts.set(attempts.get() + 1);
+            Err(())
+        });
+
+        assert_eq!(result, Err(()));
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -0,0 +1,50 @@
+//! Compares `flatten_vecs` and `flatten_reuse` against the generic `flatten` on a
+//! `Vec<Vec<u64>>`.
+//!
+//! Run with `cargo bench -p iterators`. This uses a plain `main` and `Instant` rather than
+//! a benchmarking framework, so treat the numbers as a rough comparison: each case is run
+//! a fixed number of times and the best time is reported.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use iterators::{flatten, flatten_reuse, flatten_vecs};
+
+const ROUNDS: usize = 20;
+
+fn nested(outer: usize, inner: usize) -> Vec<Vec<u64>> {
+    (0..outer)
+        .map(|i| (0..inner as u64).map(|j| i as u64 * j).collect())
+        .collect()
+}
+
+/// Runs `f` on a fresh clone of `input` each round, timing only `f`: cloning the nested
+/// input costs about as much as flattening it, and would drown out the differences.
+fn best_of<F>(input: &[Vec<u64>], mut f: F) -> Duration
+where
+    F: FnMut(Vec<Vec<u64>>) -> Vec<u64>,
+{
+    (0..ROUNDS)
+        .map(|_| {
+            let input = input.to_vec();
+            let start = Instant::now();
+            let flat = black_box(f(black_box(input)));
+            let elapsed = start.elapsed();
+            drop(flat);
+            elapsed
+        })
+        .min()
+        .expect("ROUNDS is not zero")
+}
+
+fn main() {
+    for (outer, inner) in [(1_000, 1_000), (100_000, 10), (10, 100_000)] {
+        let input = nested(outer, inner);
+        let generic = best_of(&input, |input| flatten(input).collect());
+        let vecs = best_of(&input, |input| flatten_vecs(input).collect());
+        let reuse = best_of(&input, flatten_reuse);
+        println!(
+            "{outer:>7} x {inner:<7} flatten: {generic:>12?}  flatten_vecs: {vecs:>12?}  flatten_reuse: {reuse:>12?}"
+        );
+    }
+}
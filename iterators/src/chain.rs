@@ -0,0 +1,139 @@
+use std::iter::FusedIterator;
+
+/// An iterator that yields everything from one iterator, then everything from another.
+///
+/// This struct is created by the [`our_chain`] method on [`IteratorExt`].
+///
+/// [`our_chain`]: crate::IteratorExt::our_chain
+/// [`IteratorExt`]: crate::IteratorExt
+#[derive(Clone)]
+pub struct Chain<A, B> {
+    // Like `Flatten`'s `front_iter` and `back_iter`, each side is dropped to `None` once it
+    // is exhausted, so neither is polled again after returning `None`.
+    a: Option<A>,
+    b: Option<B>,
+}
+
+impl<A, B> Chain<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Chain {
+            a: Some(a),
+            b: Some(b),
+        }
+    }
+}
+
+impl<A, B> Iterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut a) = self.a {
+            if let Some(item) = a.next() {
+                return Some(item);
+            }
+            self.a = None;
+        }
+        let item = self.b.as_mut()?.next();
+        if item.is_none() {
+            self.b = None;
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let (b_lower, b_upper) = self.b.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> DoubleEndedIterator for Chain<A, B>
+where
+    A: DoubleEndedIterator,
+    B: DoubleEndedIterator<Item = A::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut b) = self.b {
+            if let Some(item) = b.next_back() {
+                return Some(item);
+            }
+            self.b = None;
+        }
+        let item = self.a.as_mut()?.next_back();
+        if item.is_none() {
+            self.a = None;
+        }
+        item
+    }
+}
+
+impl<A, B> FusedIterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn forward() {
+        assert_eq!(
+            [1, 2].into_iter().our_chain(vec![3, 4]).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            std::iter::empty().our_chain([1]).collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(
+            [1, 2]
+                .into_iter()
+                .our_chain([3, 4])
+                .rev()
+                .collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut iter = [1, 2].into_iter().our_chain([3]);
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = [1].into_iter().our_chain([2, 3]);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn size_hint_adds_both_sides() {
+        let mut iter = [1, 2].into_iter().our_chain([3]);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        iter.next();
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!((0..).our_chain([1]).size_hint(), (usize::MAX, None));
+    }
+}
@@ -0,0 +1,40 @@
+/// Integer types that can add and multiply while reporting overflow instead of wrapping.
+///
+/// Used by [`our_sum_checked`] and [`our_product_checked`] on [`IteratorExt`].
+///
+/// [`our_sum_checked`]: crate::IteratorExt::our_sum_checked
+/// [`our_product_checked`]: crate::IteratorExt::our_product_checked
+/// [`IteratorExt`]: crate::IteratorExt
+pub trait CheckedArith: Sized {
+    /// The value a sum starts from, `0`.
+    const ZERO: Self;
+    /// The value a product starts from, `1`.
+    const ONE: Self;
+
+    /// Adds `rhs`, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies by `rhs`, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arith {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedArith for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arith!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
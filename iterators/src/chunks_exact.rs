@@ -0,0 +1,117 @@
+use std::iter::FusedIterator;
+
+/// An iterator over batches of exactly `n` consecutive elements, which keeps a trailing
+/// partial batch aside instead of yielding it.
+///
+/// This struct is created by the [`our_chunks_exact`] method on [`IteratorExt`].
+///
+/// [`our_chunks_exact`]: crate::IteratorExt::our_chunks_exact
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct ChunksExact<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    n: usize,
+    /// The elements of the last, partial batch; only filled once the input has run out.
+    remainder: Vec<I::Item>,
+    /// Set once the input has run out. Fuses the iterator, so pulling again can't
+    /// overwrite `remainder`.
+    done: bool,
+}
+
+impl<I> ChunksExact<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        assert!(n > 0, "our_chunks_exact needs a chunk size of at least 1");
+        ChunksExact {
+            iter,
+            n,
+            remainder: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Returns the elements left over after the last full batch, fewer than `n` of them.
+    ///
+    /// Like `slice::ChunksExact::remainder`, but since the input can only be read once,
+    /// this is empty until iteration has reached the end.
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+}
+
+impl<I> Iterator for ChunksExact<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let chunk: Vec<_> = self.iter.by_ref().take(self.n).collect();
+        if chunk.len() == self.n {
+            Some(chunk)
+        } else {
+            self.done = true;
+            self.remainder = chunk;
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower / self.n, upper.map(|n| n / self.n))
+    }
+}
+
+impl<I> ExactSizeIterator for ChunksExact<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for ChunksExact<I> where I: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn divides_evenly() {
+        let mut chunks = (1..7).our_chunks_exact(2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks.by_ref().collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+        assert!(chunks.remainder().is_empty());
+    }
+
+    #[test]
+    fn keeps_remainder() {
+        let mut chunks = (1..=7).our_chunks_exact(3);
+        assert_eq!(chunks.next(), Some(vec![1, 2, 3]));
+        // Not known yet: the input hasn't run out.
+        assert!(chunks.remainder().is_empty());
+        assert_eq!(chunks.next(), Some(vec![4, 5, 6]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), [7]);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), [7]);
+
+        let mut short = [1, 2].into_iter().our_chunks_exact(5);
+        assert_eq!(short.len(), 0);
+        assert_eq!(short.next(), None);
+        assert_eq!(short.remainder(), [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size of at least 1")]
+    fn zero_size() {
+        (1..3).our_chunks_exact(0);
+    }
+}
@@ -0,0 +1,77 @@
+/// An iterator that repeats an underlying iterator endlessly.
+///
+/// This struct is created by the [`our_cycle`] method on [`IteratorExt`].
+///
+/// [`our_cycle`]: crate::IteratorExt::our_cycle
+/// [`IteratorExt`]: crate::IteratorExt
+#[derive(Clone)]
+pub struct Cycle<I> {
+    /// The untouched original, cloned each time a pass is exhausted.
+    orig: I,
+    /// The pass currently being iterated.
+    iter: I,
+}
+
+impl<I> Cycle<I>
+where
+    I: Clone,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Cycle {
+            orig: iter.clone(),
+            iter,
+        }
+    }
+}
+
+impl<I> Iterator for Cycle<I>
+where
+    I: Clone + Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            None => {
+                // Start the next pass. If the fresh clone is immediately exhausted too,
+                // the underlying iterator is empty and we yield nothing instead of spinning.
+                self.iter = self.orig.clone();
+                self.iter.next()
+            }
+            item => item,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.orig.size_hint() {
+            empty @ (0, Some(0)) => empty,
+            (0, _) => (0, None),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn repeats() {
+        assert_eq!(
+            [1, 2, 3]
+                .into_iter()
+                .our_cycle()
+                .take(7)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let mut iter = std::iter::empty::<i32>().our_cycle();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,103 @@
+use std::iter::FusedIterator;
+
+/// An iterator that collapses runs of consecutive elements with equal keys.
+///
+/// This struct is created by the [`our_dedup_by_key`] method on [`IteratorExt`].
+///
+/// [`our_dedup_by_key`]: crate::IteratorExt::our_dedup_by_key
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct DedupByKey<I, F, K> {
+    iter: I,
+    key: F,
+    /// The key of the last element yielded, which the current run shares.
+    last_key: Option<K>,
+}
+
+impl<I, F, K> DedupByKey<I, F, K> {
+    pub(crate) fn new(iter: I, key: F) -> Self {
+        DedupByKey {
+            iter,
+            key,
+            last_key: None,
+        }
+    }
+}
+
+impl<I, F, K> Iterator for DedupByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let key = (self.key)(&item);
+            if self.last_key.as_ref() != Some(&key) {
+                // The first of a new run; the rest of it is skipped.
+                self.last_key = Some(key);
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // Only the very first element is certain to start a new run.
+        let lower = if self.last_key.is_none() {
+            lower.min(1)
+        } else {
+            0
+        };
+        (lower, upper)
+    }
+}
+
+impl<I, F, K> FusedIterator for DedupByKey<I, F, K>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn keeps_first_of_each_run() {
+        assert_eq!(
+            [("a", 1), ("a", 2), ("b", 3)]
+                .into_iter()
+                .our_dedup_by_key(|&(name, _)| name)
+                .collect::<Vec<_>>(),
+            vec![("a", 1), ("b", 3)]
+        );
+    }
+
+    #[test]
+    fn only_consecutive_runs() {
+        assert_eq!(
+            [1, 3, 2, 4, 5, 7]
+                .into_iter()
+                .our_dedup_by_key(|n| n % 2)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 5]
+        );
+        assert_eq!(
+            std::iter::empty::<i32>().our_dedup_by_key(|&n| n).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut iter = [1, 1, 2].into_iter().our_dedup_by_key(|&n| n);
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+    }
+}
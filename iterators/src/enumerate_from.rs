@@ -0,0 +1,81 @@
+use std::iter::FusedIterator;
+
+/// An iterator that pairs each element with a counter starting at a chosen index.
+///
+/// This struct is created by the [`our_enumerate_from`] method on [`IteratorExt`].
+///
+/// [`our_enumerate_from`]: crate::IteratorExt::our_enumerate_from
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct EnumerateFrom<I> {
+    iter: I,
+    /// The index of the next element, or `None` once the counter has run past `usize::MAX`.
+    next_index: Option<usize>,
+}
+
+impl<I> EnumerateFrom<I> {
+    pub(crate) fn new(iter: I, start: usize) -> Self {
+        EnumerateFrom {
+            iter,
+            next_index: Some(start),
+        }
+    }
+}
+
+impl<I> Iterator for EnumerateFrom<I>
+where
+    I: Iterator,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        // Only panic when an element actually needs an index past `usize::MAX`.
+        let index = self
+            .next_index
+            .expect("our_enumerate_from: index overflowed usize");
+        self.next_index = index.checked_add(1);
+        Some((index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for EnumerateFrom<I> where I: FusedIterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn starts_at_index() {
+        assert_eq!(
+            ["a", "b"]
+                .into_iter()
+                .our_enumerate_from(10)
+                .collect::<Vec<_>>(),
+            vec![(10, "a"), (11, "b")]
+        );
+    }
+
+    #[test]
+    fn last_index_is_fine() {
+        assert_eq!(
+            ["a"]
+                .into_iter()
+                .our_enumerate_from(usize::MAX)
+                .collect::<Vec<_>>(),
+            vec![(usize::MAX, "a")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn overflow_panics() {
+        ["a", "b"]
+            .into_iter()
+            .our_enumerate_from(usize::MAX)
+            .for_each(drop);
+    }
+}
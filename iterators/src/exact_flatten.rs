@@ -0,0 +1,245 @@
+use crate::Flatten;
+use std::iter::{Copied, FusedIterator};
+use std::slice;
+use std::vec;
+
+/// Flattens a slice of slices into an iterator that knows its exact length.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::flatten_slices;
+///
+/// let nested: &[&[i32]] = &[&[1, 2], &[], &[3]];
+/// let mut iter = flatten_slices(nested);
+/// assert_eq!(iter.len(), 3);
+/// iter.next();
+/// assert_eq!(iter.len(), 2);
+/// ```
+pub fn flatten_slices<'a, T>(
+    slices: &'a [&'a [T]],
+) -> ExactFlatten<Copied<slice::Iter<'a, &'a [T]>>> {
+    let len = slices.iter().map(|inner| inner.len()).sum();
+    ExactFlatten::new(slices.iter().copied(), len)
+}
+
+/// Flattens a `Vec` of `Vec`s into an iterator that knows its exact length.
+///
+/// This is the common owned case of [`flatten`](crate::flatten): the items are the same,
+/// but the total is known up front, so the iterator implements `ExactSizeIterator` and
+/// `collect` and `extend` can allocate once instead of growing as they go. In
+/// `benches/flatten.rs`, with a thousand inner `Vec`s of a thousand items each, that makes
+/// collecting it about one and a half to two times faster than `flatten`. With many short
+/// inner `Vec`s, or a few very long ones, the two take about the same time, and this one
+/// is sometimes a little slower. To reuse an inner `Vec`'s allocation instead, see
+/// [`flatten_reuse`].
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::flatten_vecs;
+///
+/// let flat: Vec<_> = flatten_vecs(vec![vec![1, 2], vec![3]]).collect();
+/// assert_eq!(flat, vec![1, 2, 3]);
+/// ```
+pub fn flatten_vecs<T>(vecs: Vec<Vec<T>>) -> ExactFlatten<vec::IntoIter<Vec<T>>> {
+    let len = vecs.iter().map(Vec::len).sum();
+    ExactFlatten::new(vecs.into_iter(), len)
+}
+
+/// Flattens a `Vec` of `Vec`s into one `Vec`, reusing the first inner `Vec`'s allocation
+/// as the output buffer.
+///
+/// Collecting [`flatten_vecs`] allocates a new buffer for the whole output and frees
+/// every inner `Vec`'s allocation after copying out of it item by item. This instead grows
+/// the first inner `Vec` to the total length and appends the others to it, so one
+/// allocation fewer is made, and each inner `Vec` is moved over in one block copy. In
+/// `benches/flatten.rs` that makes it four to five times faster when the inner `Vec`s are
+/// long, and more so if the first one already has spare capacity for the rest. With many
+/// short inner `Vec`s, freeing those dominates, and it is at best modestly faster.
+///
+/// The allocation can only be taken over because the `Vec`s are owned. If the data is
+/// shared, for example as a `&[Vec<T>]` or behind an `Rc<Vec<T>>`, other owners still
+/// point into those buffers and they must not be written to; flatten a borrow with
+/// [`flatten_ref`](crate::flatten_ref) and clone the items instead.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::flatten_reuse;
+///
+/// let mut first = Vec::with_capacity(8);
+/// first.push(1);
+/// let buffer = first.as_ptr();
+/// let flat = flatten_reuse(vec![first, vec![2, 3], vec![4]]);
+/// assert_eq!(flat, vec![1, 2, 3, 4]);
+/// assert_eq!(flat.as_ptr(), buffer);
+/// ```
+pub fn flatten_reuse<T>(vecs: Vec<Vec<T>>) -> Vec<T> {
+    let len: usize = vecs.iter().map(Vec::len).sum();
+    let mut vecs = vecs.into_iter();
+    let Some(mut flat) = vecs.next() else {
+        return Vec::new();
+    };
+    // At most one reallocation, and none if the first `Vec` already has the room.
+    flat.reserve(len - flat.len());
+    for mut inner in vecs {
+        flat.append(&mut inner);
+    }
+    flat
+}
+
+/// A [`Flatten`] that also tracks how many items are left, so it can implement
+/// [`ExactSizeIterator`].
+///
+/// The generic `Flatten` can't know the lengths of inner iterators it hasn't reached yet,
+/// so the total is computed up front when the inner lengths are cheap to get (as with
+/// slices), and counted down as items are pulled from either end.
+///
+/// This struct is created by the [`flatten_slices`] and [`flatten_vecs`] functions.
+pub struct ExactFlatten<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    inner: Flatten<O>,
+    remaining: usize,
+}
+
+impl<O> ExactFlatten<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    // `len` must be the total number of items across all inner iterators of `outer`.
+    fn new(outer: O, len: usize) -> Self {
+        ExactFlatten {
+            inner: Flatten::new(outer),
+            remaining: len,
+        }
+    }
+}
+
+impl<O> Iterator for ExactFlatten<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    type Item = <O::Item as IntoIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<O> DoubleEndedIterator for ExactFlatten<O>
+where
+    O: DoubleEndedIterator,
+    O::Item: IntoIterator,
+    <O::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next_back()?;
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+impl<O> ExactSizeIterator for ExactFlatten<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+}
+
+impl<O> FusedIterator for ExactFlatten<O>
+where
+    O: FusedIterator,
+    O::Item: IntoIterator,
+    <O::Item as IntoIterator>::IntoIter: FusedIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_matches_count() {
+        let nested: &[&[i32]] = &[&[1, 2], &[], &[3, 4, 5], &[6]];
+        assert_eq!(flatten_slices(nested).len(), 6);
+        assert_eq!(flatten_slices(nested).count(), 6);
+        assert_eq!(flatten_slices::<i32>(&[]).len(), 0);
+        assert_eq!(flatten_slices::<i32>(&[&[], &[]]).len(), 0);
+    }
+
+    #[test]
+    fn reuse_matches_generic_flatten() {
+        let cases: Vec<Vec<Vec<i32>>> = vec![
+            vec![],
+            vec![vec![], vec![]],
+            vec![vec![], vec![1]],
+            vec![vec![1, 2], vec![], vec![3, 4, 5], vec![6]],
+        ];
+        for nested in cases {
+            let expected: Vec<_> = crate::flatten(nested.clone()).collect();
+            assert_eq!(flatten_reuse(nested), expected);
+        }
+    }
+
+    #[test]
+    fn reuse_keeps_first_allocation() {
+        let mut first = Vec::with_capacity(10);
+        first.extend([String::from("a"), String::from("b")]);
+        let buffer = first.as_ptr();
+        let flat = flatten_reuse(vec![first, vec![String::from("c")], Vec::new()]);
+        assert_eq!(flat, ["a", "b", "c"]);
+        // There was room for everything, so nothing was reallocated.
+        assert_eq!(flat.as_ptr(), buffer);
+        assert_eq!(flat.capacity(), 10);
+    }
+
+    #[test]
+    fn vecs_match_generic_flatten() {
+        let cases: Vec<Vec<Vec<i32>>> = vec![
+            vec![],
+            vec![vec![], vec![]],
+            vec![vec![1, 2], vec![], vec![3, 4, 5], vec![6]],
+        ];
+        for nested in cases {
+            let expected: Vec<_> = crate::flatten(nested.clone()).collect();
+            let mut iter = flatten_vecs(nested.clone());
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.by_ref().collect::<Vec<_>>(), expected);
+            assert_eq!(iter.len(), 0);
+
+            let reversed: Vec<_> = flatten_vecs(nested).rev().collect();
+            assert_eq!(reversed, expected.into_iter().rev().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn len_decrements_from_both_ends() {
+        let nested: &[&[i32]] = &[&[1, 2], &[], &[3, 4, 5], &[6]];
+        let mut iter = flatten_slices(nested);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+}
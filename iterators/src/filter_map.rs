@@ -0,0 +1,91 @@
+use std::iter::FusedIterator;
+
+/// An iterator that maps elements with a closure and keeps only the `Some` results.
+///
+/// This struct is created by the [`our_filter_map`] method on [`IteratorExt`].
+///
+/// [`our_filter_map`]: crate::IteratorExt::our_filter_map
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct FilterMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> FilterMap<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        FilterMap { iter, f }
+    }
+}
+
+impl<B, I, F> Iterator for FilterMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Keep pulling until `f` accepts an element, or the underlying iterator runs out.
+        loop {
+            let item = self.iter.next()?;
+            if let Some(mapped) = (self.f)(item) {
+                return Some(mapped);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `f` might reject every element, or none of them.
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<B, I, F> DoubleEndedIterator for FilterMap<I, F>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> Option<B>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next_back()?;
+            if let Some(mapped) = (self.f)(item) {
+                return Some(mapped);
+            }
+        }
+    }
+}
+
+impl<B, I, F> FusedIterator for FilterMap<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item) -> Option<B>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn keeps_parsed() {
+        let parsed: Vec<i32> = ["1", "x", "3"]
+            .into_iter()
+            .our_filter_map(|s| s.parse().ok())
+            .collect();
+        assert_eq!(parsed, vec![1, 3]);
+
+        let reversed: Vec<i32> = ["1", "x", "3"]
+            .into_iter()
+            .our_filter_map(|s| s.parse().ok())
+            .rev()
+            .collect();
+        assert_eq!(reversed, vec![3, 1]);
+    }
+
+    #[test]
+    fn size_hint_upper_only() {
+        let iter = [1, 2, 3].into_iter().our_filter_map(Some);
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_eq!((0..).our_filter_map(Some).size_hint(), (0, None));
+    }
+}
@@ -0,0 +1,119 @@
+use std::iter::FusedIterator;
+
+/// An iterator that flattens one level, pairing each item with the index of the outer
+/// element it came from.
+///
+/// It only iterates forwards: counting outer elements from the back would need to know
+/// how many there are.
+///
+/// This struct is created by the [`our_flatten_indexed`] method on [`IteratorExt`].
+///
+/// [`our_flatten_indexed`]: crate::IteratorExt::our_flatten_indexed
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct FlattenIndexed<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    outer: O,
+    /// The inner iterator being drained, with the index of the outer element it came from.
+    front: Option<(usize, <O::Item as IntoIterator>::IntoIter)>,
+    /// The index the next outer element pulled will get.
+    next_index: usize,
+}
+
+impl<O> FlattenIndexed<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    pub(crate) fn new(outer: O) -> Self {
+        FlattenIndexed {
+            outer,
+            front: None,
+            next_index: 0,
+        }
+    }
+}
+
+impl<O> Iterator for FlattenIndexed<O>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    type Item = (usize, <O::Item as IntoIterator>::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((index, ref mut inner)) = self.front {
+                if let Some(item) = inner.next() {
+                    return Some((index, item));
+                }
+            }
+            // Empty inner iterables still use up an index.
+            let inner = self.outer.next()?.into_iter();
+            self.front = Some((self.next_index, inner));
+            self.next_index += 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (front_lower, front_upper) = match &self.front {
+            Some((_, inner)) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        // The outer elements left could hold any number of items, unless there are none.
+        match self.outer.size_hint() {
+            (_, Some(0)) => (front_lower, front_upper),
+            _ => (front_lower, None),
+        }
+    }
+}
+
+impl<O> FusedIterator for FlattenIndexed<O>
+where
+    O: FusedIterator,
+    O::Item: IntoIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn pairs_items_with_outer_index() {
+        assert_eq!(
+            [["a", "b"].as_slice(), ["c"].as_slice()]
+                .into_iter()
+                .our_flatten_indexed()
+                .map(|(i, &s)| (i, s))
+                .collect::<Vec<_>>(),
+            vec![(0, "a"), (0, "b"), (1, "c")]
+        );
+    }
+
+    #[test]
+    fn empty_inner_uses_an_index() {
+        let nested = vec![vec![], vec![1], vec![], vec![2, 3]];
+        assert_eq!(
+            nested.into_iter().our_flatten_indexed().collect::<Vec<_>>(),
+            vec![(1, 1), (3, 2), (3, 3)]
+        );
+        assert_eq!(
+            std::iter::empty::<Vec<i32>>().our_flatten_indexed().next(),
+            None
+        );
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut iter = vec![vec![1, 2], vec![3]].into_iter().our_flatten_indexed();
+        assert_eq!(iter.size_hint(), (0, None));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, None));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
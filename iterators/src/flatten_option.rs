@@ -0,0 +1,86 @@
+use std::iter::FusedIterator;
+
+/// An iterator over the values inside the `Some`s of an iterator of `Option`s.
+///
+/// This struct is created by the [`our_flatten_option`] method on [`IteratorExt`].
+///
+/// [`our_flatten_option`]: crate::IteratorExt::our_flatten_option
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct FlattenOption<I> {
+    iter: I,
+}
+
+impl<I> FlattenOption<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        FlattenOption { iter }
+    }
+}
+
+impl<I, T> Iterator for FlattenOption<I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip the `None`s, until a `Some` or the end of the underlying iterator.
+        loop {
+            if let Some(value) = self.iter.next()? {
+                return Some(value);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every element left could be a `None`.
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T> DoubleEndedIterator for FlattenOption<I>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.iter.next_back()? {
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<I, T> FusedIterator for FlattenOption<I> where I: FusedIterator<Item = Option<T>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn keeps_somes() {
+        let values = [Some(1), None, Some(3)];
+        assert_eq!(
+            values.into_iter().our_flatten_option().collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(
+            values
+                .into_iter()
+                .our_flatten_option()
+                .rev()
+                .collect::<Vec<_>>(),
+            vec![3, 1]
+        );
+        assert_eq!(
+            [None::<i32>, None].into_iter().our_flatten_option().next(),
+            None
+        );
+    }
+
+    #[test]
+    fn size_hint_upper_only() {
+        let iter = [Some(1), None].into_iter().our_flatten_option();
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+        assert_eq!((0..).map(Some).our_flatten_option().size_hint(), (0, None));
+    }
+}
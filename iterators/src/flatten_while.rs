@@ -0,0 +1,107 @@
+use crate::Flatten;
+use std::iter::FusedIterator;
+
+/// An iterator that flattens one level and stops for good at the first item rejected by a predicate.
+///
+/// It only iterates forwards: there is no sensible "back" for a take-while, and leaving
+/// `DoubleEndedIterator` out means a stopped iterator can't resume from the other end.
+///
+/// This struct is created by the [`our_flatten_while`] method on [`IteratorExt`].
+///
+/// [`our_flatten_while`]: crate::IteratorExt::our_flatten_while
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct FlattenWhile<O, P>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    inner: Flatten<O>,
+    pred: P,
+    /// Set once `pred` rejects an item (or the input runs out); fuses the iterator.
+    done: bool,
+}
+
+impl<O, P> FlattenWhile<O, P>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+{
+    pub(crate) fn new(iter: O, pred: P) -> Self {
+        FlattenWhile {
+            inner: Flatten::new(iter),
+            pred,
+            done: false,
+        }
+    }
+}
+
+impl<O, P> Iterator for FlattenWhile<O, P>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+    P: FnMut(&<O::Item as IntoIterator>::Item) -> bool,
+{
+    type Item = <O::Item as IntoIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.inner.next() {
+            Some(item) if (self.pred)(&item) => Some(item),
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<O, P> FusedIterator for FlattenWhile<O, P>
+where
+    O: Iterator,
+    O::Item: IntoIterator,
+    P: FnMut(&<O::Item as IntoIterator>::Item) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn stops_mid_inner() {
+        let nested = vec![vec![1, 2], vec![3, 0, 4], vec![5]];
+        let mut iter = nested.into_iter().our_flatten_while(|&x| x != 0);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        // 4 and 5 are still in the underlying iterators, but we stay stopped.
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn never_stops() {
+        let nested = vec![vec![1, 2], vec![], vec![3]];
+        assert_eq!(
+            nested
+                .into_iter()
+                .our_flatten_while(|_| true)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn stops_at_first() {
+        let nested = vec![vec!["stop", "a"], vec!["b"]];
+        assert_eq!(
+            nested
+                .into_iter()
+                .our_flatten_while(|&s| s != "stop")
+                .count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,72 @@
+/// Creates an iterator that calls `f` for each element, stopping when it returns `None`.
+///
+/// Mirrors `std::iter::from_fn`. The closure holds all of the iterator's state in its
+/// captures, so it is the shortest way to write a one-off iterator without a struct.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::our_from_fn;
+///
+/// let mut count = 0;
+/// let counter: Vec<_> = our_from_fn(|| {
+///     count += 1;
+///     (count <= 3).then_some(count)
+/// })
+/// .collect();
+/// assert_eq!(counter, vec![1, 2, 3]);
+/// ```
+pub fn our_from_fn<T, F>(f: F) -> FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    FromFn { f }
+}
+
+/// An iterator whose elements are produced by a closure.
+///
+/// This struct is created by the [`our_from_fn`] function.
+pub struct FromFn<F> {
+    f: F,
+}
+
+impl<T, F> Iterator for FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.f)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_up_to_three() {
+        let mut next = 0;
+        let counted: Vec<_> = our_from_fn(|| {
+            let n = next;
+            next += 1;
+            (n < 3).then_some(n)
+        })
+        .collect();
+        assert_eq!(counted, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn not_fused() {
+        // Nothing stops the closure from producing more after a `None`.
+        let mut calls = 0;
+        let mut iter = our_from_fn(|| {
+            calls += 1;
+            (calls != 2).then_some(calls)
+        });
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(3));
+    }
+}
@@ -0,0 +1,126 @@
+use std::iter::FusedIterator;
+
+/// An iterator that puts a freshly generated separator between each pair of elements.
+///
+/// This struct is created by the [`our_intersperse_with`] method on [`IteratorExt`].
+///
+/// [`our_intersperse_with`]: crate::IteratorExt::our_intersperse_with
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct IntersperseWith<I, G>
+where
+    I: Iterator,
+{
+    iter: I,
+    separator: G,
+    /// An element pulled early to find out whether a separator is needed before it.
+    peeked: Option<I::Item>,
+    /// Whether the last thing yielded was an element, so a separator comes next if any
+    /// element follows.
+    needs_separator: bool,
+}
+
+impl<I, G> IntersperseWith<I, G>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, separator: G) -> Self {
+        IntersperseWith {
+            iter,
+            separator,
+            peeked: None,
+            needs_separator: false,
+        }
+    }
+}
+
+impl<I, G> Iterator for IntersperseWith<I, G>
+where
+    I: Iterator,
+    G: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator {
+            // Only generate a separator if there is something to separate, so there is
+            // never a trailing one.
+            self.peeked = Some(self.iter.next()?);
+            self.needs_separator = false;
+            return Some((self.separator)());
+        }
+        let item = self.peeked.take().or_else(|| self.iter.next())?;
+        self.needs_separator = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let peeked = usize::from(self.peeked.is_some());
+        // With `n` elements left in `iter`: right after an element, each of them comes
+        // with a separator (2n); right after a separator, the peeked element comes first
+        // (2n + 1); at the start, the first one has no separator (2n - 1).
+        let remaining = |n: usize| -> Option<usize> {
+            if self.needs_separator || peeked == 1 {
+                n.checked_mul(2)?.checked_add(peeked)
+            } else {
+                Some((n.checked_mul(2)?).saturating_sub(1))
+            }
+        };
+        (
+            remaining(lower).unwrap_or(usize::MAX),
+            upper.and_then(remaining),
+        )
+    }
+}
+
+impl<I, G> FusedIterator for IntersperseWith<I, G>
+where
+    I: FusedIterator,
+    G: FnMut() -> I::Item,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn counter_separator() {
+        let mut count = 0;
+        let items: Vec<_> = [10, 20, 30]
+            .into_iter()
+            .our_intersperse_with(|| {
+                count += 1;
+                count
+            })
+            .collect();
+        assert_eq!(items, vec![10, 1, 20, 2, 30]);
+    }
+
+    #[test]
+    fn no_separator_at_ends() {
+        let sep = || String::from(",");
+        let empty: Vec<String> = Vec::new();
+        assert!(empty.into_iter().our_intersperse_with(sep).next().is_none());
+        let one: Vec<_> = vec![String::from("a")]
+            .into_iter()
+            .our_intersperse_with(sep)
+            .collect();
+        assert_eq!(one, ["a"]);
+    }
+
+    #[test]
+    fn size_hint_is_exact() {
+        let mut iter = [1, 2, 3].into_iter().our_intersperse_with(|| 0);
+        for expected in (0..=5).rev() {
+            assert_eq!(iter.size_hint(), (expected, Some(expected)));
+            iter.next();
+        }
+        assert_eq!(
+            std::iter::empty::<i32>()
+                .our_intersperse_with(|| 0)
+                .size_hint(),
+            (0, Some(0))
+        );
+    }
+}
@@ -1,113 +1,1259 @@
-/// # Iteration Example
-///
-/// In Rust, the `for` loop is syntactic sugar for iterating over items using an iterator.
-/// Here’s an example of a `for` loop:
-///
-/// ```rust
-/// for x in vec!["a", "b", "c"] {
-///     // do something with x
-/// }
-/// ```
-///
-/// This is actually syntactic sugar for the following code:
-///
-/// ```rust
-/// let mut iter = vec!["a", "b", "c"].into_iter();
-/// while let Some(e) = iter.next() {
-///     // do something with e
-/// }
-/// ```
-///
-/// The `for` loop implicitly calls the `.into_iter()` method to create an iterator,
-/// and then uses the `while let Some` pattern to iterate over the elements. The iterator's
-/// `.next()` method is called in each iteration to get the next element, and when the
-/// iterator is exhausted (returns `None`), the loop ends.
+//! # Iteration Example
+//!
+//! In Rust, the `for` loop is syntactic sugar for iterating over items using an iterator.
+//! Here’s an example of a `for` loop:
+//!
+//! ```rust
+//! for x in vec!["a", "b", "c"] {
+//!     // do something with x
+//! }
+//! ```
+//!
+//! This is actually syntactic sugar for the following code:
+//!
+//! ```rust
+//! let mut iter = vec!["a", "b", "c"].into_iter();
+//! while let Some(e) = iter.next() {
+//!     // do something with e
+//! }
+//! ```
+//!
+//! The `for` loop implicitly calls the `.into_iter()` method to create an iterator,
+//! and then uses the `while let Some` pattern to iterate over the elements. The iterator's
+//! `.next()` method is called in each iteration to get the next element, and when the
+//! iterator is exhausted (returns `None`), the loop ends.
+//!
+//! # Iterating Over a Vector in Rust
+//!
+//! There are multiple ways to iterate over a `Vec` in Rust, each with different ownership semantics.
+//! Let’s consider a vector `vs` and three ways of iterating over it.
+//!
+//! ```rust
+//! let vs = vec![1, 2, 3];
+//! ```
+//!
+//! ## 1. Consuming the Vector (Ownership Transfer)
+//!
+//! When you use `for v in vs`, the vector `vs` is *consumed*:
+//!
+//! ```rust
+//! for v in vs {
+//!     // consumes `vs`, ownership of each element is moved into `v`
+//! }
+//! ```
+//!
+//! In this case, `vs` is no longer usable after the loop because ownership of the vector is moved
+//! into the loop. This uses the `into_iter` method implicitly, which transfers ownership of each element.
+//!
+//! ## 2. Borrowing the Vector’s Elements (Immutable References)
+//!
+//! You can borrow each element of the vector without consuming it using `vs.iter()`:
+//!
+//! ```rust
+//! for v in vs.iter() {
+//!     // borrows `vs`, `v` is an immutable reference (&i32)
+//! }
+//! ```
+//!
+//! Here, `vs` is not consumed, and the elements are accessed as immutable references (`&i32`).
+//! After the loop, `vs` is still available for use.
+//!
+//! ## 3. Borrowing the Entire Vector (Equivalent to `iter()`)
+//!
+//! Alternatively, you can iterate over references to the elements of the vector using `&vs`:
+//!
+//! ```rust
+//! for v in &vs {
+//!     // equivalent to `vs.iter()`, `v` is an immutable reference (&i32)
+//! }
+//! ```
+//!
+//! This syntax is a shorthand for calling `vs.iter()`, and it behaves the same by borrowing the
+//! vector and giving you immutable references to its elements.
+//!
+//! ## Source
+//!
+//! For a detailed explanation of the differences between `iter`, `into_iter`, and `&` on vectors,
+//! you can refer to this helpful discussion:
+//! [StackOverflow: What is the difference between iter and into_iter?](https://stackoverflow.com/questions/34733811/what-is-the-difference-between-iter-and-into-iter)
 
-/// # Iterating Over a Vector in Rust
-///
-/// There are multiple ways to iterate over a `Vec` in Rust, each with different ownership semantics.
-/// Let’s consider a vector `vs` and three ways of iterating over it.
-///
-/// ```rust
-/// let vs = vec![1, 2, 3];
-/// ```
-///
-/// ## 1. Consuming the Vector (Ownership Transfer)
-///
-/// When you use `for v in vs`, the vector `vs` is *consumed*:
-///
-/// ```rust
-/// for v in vs {
-///     // consumes `vs`, ownership of each element is moved into `v`
-/// }
-/// ```
-///
-/// In this case, `vs` is no longer usable after the loop because ownership of the vector is moved
-/// into the loop. This uses the `into_iter` method implicitly, which transfers ownership of each element.
-///
-/// ## 2. Borrowing the Vector’s Elements (Immutable References)
-///
-/// You can borrow each element of the vector without consuming it using `vs.iter()`:
-///
-/// ```rust
-/// for v in vs.iter() {
-///     // borrows `vs`, `v` is an immutable reference (&i32)
-/// }
-/// ```
-///
-/// Here, `vs` is not consumed, and the elements are accessed as immutable references (`&i32`).
-/// After the loop, `vs` is still available for use.
-///
-/// ## 3. Borrowing the Entire Vector (Equivalent to `iter()`)
-///
-/// Alternatively, you can iterate over references to the elements of the vector using `&vs`:
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+mod chain;
+mod checked;
+mod chunks_exact;
+mod cycle;
+mod dedup_by_key;
+mod enumerate_from;
+mod exact_flatten;
+mod filter_map;
+mod flatten_indexed;
+mod flatten_option;
+mod flatten_while;
+mod from_fn;
+mod intersperse_with;
+mod map_while;
+mod peekable;
+mod successors;
+mod take;
+mod tree;
+mod try_flatten;
+mod tuple_windows;
+
+pub use chain::Chain;
+pub use checked::CheckedArith;
+pub use chunks_exact::ChunksExact;
+pub use cycle::Cycle;
+pub use dedup_by_key::DedupByKey;
+pub use enumerate_from::EnumerateFrom;
+pub use exact_flatten::{flatten_reuse, flatten_slices, flatten_vecs, ExactFlatten};
+pub use filter_map::FilterMap;
+pub use flatten_indexed::FlattenIndexed;
+pub use flatten_option::FlattenOption;
+pub use flatten_while::FlattenWhile;
+pub use from_fn::{our_from_fn, FromFn};
+pub use intersperse_with::IntersperseWith;
+pub use map_while::MapWhile;
+pub use peekable::Peekable;
+pub use successors::{our_successors, Successors};
+pub use take::Take;
+pub use tree::{Leaves, Tree};
+pub use try_flatten::TryFlatten;
+pub use tuple_windows::TupleWindows;
+
+/// An extension trait for iterators that provides the hand-rolled `our_*` adapters.
 ///
-/// ```rust
-/// for v in &vs {
-///     // equivalent to `vs.iter()`, `v` is an immutable reference (&i32)
-/// }
-/// ```
+/// # Fused adapters
 ///
-/// This syntax is a shorthand for calling `vs.iter()`, and it behaves the same by borrowing the
-/// vector and giving you immutable references to its elements.
+/// These adapters implement [`FusedIterator`](std::iter::FusedIterator): once they return
+/// `None`, they keep returning `None`.
 ///
-/// ## Source
+/// - Always, even over an iterator that isn't fused itself: [`Take`], [`Chain`],
+///   [`ChunksExact`], [`MapWhile`], [`FlattenWhile`], and the generated [`Successors`] and
+///   [`Leaves`].
+/// - When the underlying iterator is fused: [`DedupByKey`], [`EnumerateFrom`],
+///   [`FilterMap`], [`FlattenIndexed`] (only the outer iterator matters), [`FlattenOption`],
+///   [`IntersperseWith`], [`Peekable`], [`TupleWindows`] and [`TryFlatten`] (which also
+///   always stops after yielding an error).
+/// - When the outer iterator and the inner iterators are fused: [`Flatten`] and
+///   [`ExactFlatten`].
 ///
-/// For a detailed explanation of the differences between `iter`, `into_iter`, and `&` on vectors,
-/// you can refer to this helpful discussion:
-/// [StackOverflow: What is the difference between iter and into_iter?](https://stackoverflow.com/questions/34733811/what-is-the-difference-between-iter-and-into-iter)
+/// [`Cycle`] is intentionally infinite, unless the underlying iterator is empty, so it
+/// doesn't implement it. Neither does [`FromFn`], whose closure may produce more elements
+/// after returning `None`.
+pub trait IteratorExt: Iterator {
+    /// Flattens an iterator of iterables into a single iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use your_crate_name::IteratorExt; // Replace `your_crate_name` with your crate's name
+    ///
+    /// let nested = vec![vec![1, 2], vec![3, 4]];
+    /// let flat_iter = nested.into_iter().our_flatten();
+    ///
+    /// let collected: Vec<_> = flat_iter.collect();
+    /// assert_eq!(collected, vec![1, 2, 3, 4]);
+    /// ```
+    fn our_flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator;
+
+    /// Repeats the iterator endlessly, starting over from a clone of the original each
+    /// time it runs out. An empty iterator stays empty instead of looping forever.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let cycled: Vec<_> = [1, 2, 3].into_iter().our_cycle().take(7).collect();
+    /// assert_eq!(cycled, vec![1, 2, 3, 1, 2, 3, 1]);
+    /// ```
+    fn our_cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone;
+
+    /// Splits an iterator of pairs into two collections, like `Iterator::unzip`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let (numbers, letters): (Vec<_>, Vec<_>) = vec![(1, 'a'), (2, 'b')].into_iter().our_unzip();
+    /// assert_eq!(numbers, vec![1, 2]);
+    /// assert_eq!(letters, vec!['a', 'b']);
+    /// ```
+    fn our_unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        Self: Sized + Iterator<Item = (A, B)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>;
+
+    /// Flattens an iterator of `Result`s of iterables, yielding each inner item as `Ok`.
+    ///
+    /// The first `Err` is yielded as-is, after which the iterator is fused and yields nothing more.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let nested = vec![Ok(vec![1, 2]), Err("bad"), Ok(vec![3])];
+    /// let flat: Vec<_> = nested.into_iter().our_try_flatten().collect();
+    /// assert_eq!(flat, vec![Ok(1), Ok(2), Err("bad")]);
+    /// ```
+    fn our_try_flatten<II, E>(self) -> TryFlatten<Self, II, E>
+    where
+        Self: Sized,
+        Self::Item: Into<Result<II, E>>,
+        II: IntoIterator;
+
+    /// Pairs each element with its index like `enumerate`, but counting from `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element's index would overflow `usize`, rather than wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let indexed: Vec<_> = ["a", "b"].into_iter().our_enumerate_from(10).collect();
+    /// assert_eq!(indexed, vec![(10, "a"), (11, "b")]);
+    /// ```
+    fn our_enumerate_from(self, start: usize) -> EnumerateFrom<Self>
+    where
+        Self: Sized;
+
+    /// Flattens one level like [`our_flatten`](IteratorExt::our_flatten), yielding items
+    /// only while `pred` returns `true`. After the first rejected item it yields nothing more.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let nested = vec![vec![1, 2], vec![3, 0, 4]];
+    /// let taken: Vec<_> = nested.into_iter().our_flatten_while(|&x| x != 0).collect();
+    /// assert_eq!(taken, vec![1, 2, 3]);
+    /// ```
+    fn our_flatten_while<P>(self, pred: P) -> FlattenWhile<Self, P>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+        P: FnMut(&<Self::Item as IntoIterator>::Item) -> bool;
+
+    /// Sums the elements, returning `None` instead of panicking or wrapping on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!([1u8, 2, 3].into_iter().our_sum_checked(), Some(6));
+    /// assert_eq!([200u8, 100].into_iter().our_sum_checked(), None);
+    /// ```
+    fn our_sum_checked(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedArith;
+
+    /// Multiplies the elements, returning `None` instead of panicking or wrapping on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!([2i8, 3, 4].into_iter().our_product_checked(), Some(24));
+    /// assert_eq!([16i8, 8].into_iter().our_product_checked(), None);
+    /// ```
+    fn our_product_checked(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedArith;
+
+    /// Returns the index and value of the element with the largest key.
+    ///
+    /// If several elements share the largest key, the last one wins, matching
+    /// `Iterator::max_by_key`. Returns `None` for an empty iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let words = ["a", "ccc", "bb", "ddd"];
+    /// assert_eq!(words.into_iter().our_arg_max_by_key(|w| w.len()), Some((3, "ddd")));
+    /// ```
+    fn our_arg_max_by_key<B, F>(self, f: F) -> Option<(usize, Self::Item)>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Item) -> B;
+
+    /// Folds the elements together, using the first one as the initial accumulator.
+    ///
+    /// Returns `None` for an empty iterator, matching [`Iterator::reduce`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().our_reduce(|a, b| a * b), Some(6));
+    /// ```
+    fn our_reduce<F>(self, f: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item;
+
+    /// Folds the elements into an accumulator, stopping at the first `Err`.
+    ///
+    /// Elements after the failing one are not pulled from the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let total = ["1", "2", "3"]
+    ///     .into_iter()
+    ///     .our_try_fold(0, |acc, s| s.parse::<i32>().map(|n| acc + n));
+    /// assert_eq!(total, Ok(6));
+    /// ```
+    fn our_try_fold<B, E, F>(self, init: B, f: F) -> Result<B, E>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> Result<B, E>;
+
+    /// Borrows the iterator, so an adapter can consume part of it and the rest can still
+    /// be used afterwards.
+    ///
+    /// This works because `&mut I` is itself an iterator whenever `I` is; the standard
+    /// library's `by_ref` does the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter();
+    /// assert_eq!(iter.our_by_ref().our_take(1).collect::<Vec<_>>(), vec![1]);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    fn our_by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized;
+
+    /// Creates an iterator that yields at most the first `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!((1..).our_take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn our_take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized;
+
+    /// Concatenates the elements into a `String`, with no separator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!(["ab", "c"].into_iter().our_collect_string(), "abc");
+    /// ```
+    fn our_collect_string(self) -> String
+    where
+        Self: Sized,
+        Self::Item: AsRef<str>;
+
+    /// Creates an iterator that maps elements with `predicate` until it first returns `None`.
+    ///
+    /// Like [`Iterator::map_while`], the iterator stays exhausted after that.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let prefix: Vec<i32> = ["1", "x", "2"].into_iter().our_map_while(|s| s.parse().ok()).collect();
+    /// assert_eq!(prefix, vec![1]);
+    /// ```
+    fn our_map_while<B, P>(self, predicate: P) -> MapWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> Option<B>;
+
+    /// Returns the `n`th element (counting from zero), consuming it and the `n` elements
+    /// before it.
+    ///
+    /// The iterator is left positioned right after the returned element, so calling this
+    /// repeatedly walks forward. Returns `None` if fewer than `n + 1` elements remain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter();
+    /// assert_eq!(iter.our_nth(1), Some(2));
+    /// assert_eq!(iter.our_nth(0), Some(3));
+    /// ```
+    fn our_nth(&mut self, n: usize) -> Option<Self::Item>;
+
+    /// Creates an iterator that yields all of `self`, then all of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let all: Vec<_> = [1, 2].into_iter().our_chain(vec![3]).collect();
+    /// assert_eq!(all, vec![1, 2, 3]);
+    /// ```
+    fn our_chain<U>(self, other: U) -> Chain<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator<Item = Self::Item>;
+
+    /// Counts the elements for which `predicate` returns `true`, in a single pass.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!("a1b2c3".chars().our_count_where(char::is_ascii_digit), 3);
+    /// ```
+    fn our_count_where<P>(self, predicate: P) -> usize
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool;
+
+    /// Returns the elements in reverse order, even if the iterator isn't double-ended.
+    ///
+    /// This buffers: every element is first collected into a `Vec` (one allocation of the
+    /// full length), which is then reversed in place. Prefer `.rev()` whenever the
+    /// iterator is a `DoubleEndedIterator`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let evens = (1..=6).filter(|x| x % 2 == 0);
+    /// assert_eq!(evens.our_rev_collected().collect::<Vec<_>>(), vec![6, 4, 2]);
+    /// ```
+    fn our_rev_collected(self) -> std::vec::IntoIter<Self::Item>
+    where
+        Self: Sized;
+
+    /// Returns `true` if every element equals the first one.
+    ///
+    /// An empty iterator has no elements that differ, so it returns `true`. Stops at the
+    /// first element that differs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert!(["a", "a"].into_iter().our_all_equal());
+    /// assert!(![1, 2].into_iter().our_all_equal());
+    /// ```
+    fn our_all_equal(self) -> bool
+    where
+        Self: Sized,
+        Self::Item: PartialEq;
+
+    /// Applies `f` to elements until it returns `Some`, and returns that value.
+    ///
+    /// Stops at the first `Some`, leaving the iterator positioned right after the element
+    /// that produced it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let mut iter = ["a", "1", "2"].into_iter();
+    /// assert_eq!(iter.our_find_map(|s| s.parse::<i32>().ok()), Some(1));
+    /// ```
+    fn our_find_map<B, F>(&mut self, f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>;
+
+    /// Returns the index of the first element for which `predicate` returns `true`.
+    ///
+    /// Elements up to and including the match are consumed, so the iterator can be
+    /// resumed after it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!(["a", "b"].into_iter().our_position(|s| s == "b"), Some(1));
+    /// ```
+    fn our_position<P>(&mut self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(Self::Item) -> bool;
+
+    /// Returns the smallest and largest elements, found in a single pass.
+    ///
+    /// Elements are compared in pairs, which takes about `3n/2` comparisons instead of the
+    /// `2n` of calling `min` and `max` separately. As with those, ties go to the first
+    /// minimum and the last maximum. A single element is both, so it is cloned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().our_min_max(), Some((1, 5)));
+    /// ```
+    fn our_min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone;
+
+    /// Sums `f` applied to each element; shorthand for `.map(f).sum()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let total: usize = ["a", "bb"].into_iter().our_sum_by_key(str::len);
+    /// assert_eq!(total, 3);
+    /// ```
+    fn our_sum_by_key<B, F>(self, f: F) -> B
+    where
+        Self: Sized,
+        B: std::iter::Sum,
+        F: FnMut(Self::Item) -> B;
+
+    /// Creates an iterator that places a separator generated by `separator` between each
+    /// pair of elements.
+    ///
+    /// Unlike a fixed separator, this doesn't need the item type to be `Clone`, and the
+    /// separator can change each time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let words: String = ["a", "b", "c"].into_iter().our_intersperse_with(|| "-").collect();
+    /// assert_eq!(words, "a-b-c");
+    /// ```
+    fn our_intersperse_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    where
+        Self: Sized,
+        G: FnMut() -> Self::Item;
+
+    /// Creates an iterator that applies `f` to each element and yields only the `Some`
+    /// results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let evens: Vec<_> = (1..=6).our_filter_map(|x| (x % 2 == 0).then_some(x * 10)).collect();
+    /// assert_eq!(evens, vec![20, 40, 60]);
+    /// ```
+    fn our_filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>;
+
+    /// Creates an iterator over each pair of consecutive elements, as tuples.
+    ///
+    /// Each element after the first is cloned once, to start the next pair; no `Vec` is
+    /// allocated. Fewer than two elements give no pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let steps: Vec<_> = [1, 4, 9].into_iter().our_tuple_windows().map(|(a, b)| b - a).collect();
+    /// assert_eq!(steps, vec![3, 5]);
+    /// ```
+    fn our_tuple_windows(self) -> TupleWindows<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone;
+
+    /// Collects the last `n` elements, in their original order.
+    ///
+    /// Only `n` elements are held at a time, in a ring buffer that overwrites the oldest
+    /// one, so tailing a long iterator doesn't collect all of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// assert_eq!((1..=100).our_last_n(3), vec![98, 99, 100]);
+    /// ```
+    fn our_last_n(self, n: usize) -> Vec<Self::Item>
+    where
+        Self: Sized;
+
+    /// Folds the elements into an accumulator like `fold`, also passing each element's
+    /// index, counted from 0.
+    ///
+    /// This saves an `enumerate` step and the tuple destructuring that comes with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let weighted = [10, 20, 30]
+    ///     .into_iter()
+    ///     .our_enumerate_fold(0, |acc, i, x| acc + i * x);
+    /// assert_eq!(weighted, 80);
+    /// ```
+    fn our_enumerate_fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, usize, Self::Item) -> B;
+
+    /// Creates an iterator that collapses each run of consecutive elements whose keys
+    /// are equal into the first element of the run.
+    ///
+    /// Like `Vec::dedup_by_key`, only neighbours are compared, so equal keys that are
+    /// apart are both kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let words: Vec<_> = ["apple", "avocado", "banana", "apricot"]
+    ///     .into_iter()
+    ///     .our_dedup_by_key(|s| s.chars().next())
+    ///     .collect();
+    /// assert_eq!(words, vec!["apple", "banana", "apricot"]);
+    /// ```
+    fn our_dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K;
+
+    /// Flattens an iterator of iterables, pairing each item with the index of the outer
+    /// element it came from.
+    ///
+    /// The index counts every outer element, including ones that turn out to be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let lines = vec![vec!["a", "b"], vec!["c"]];
+    /// let flat: Vec<_> = lines.into_iter().our_flatten_indexed().collect();
+    /// assert_eq!(flat, vec![(0, "a"), (0, "b"), (1, "c")]);
+    /// ```
+    fn our_flatten_indexed(self) -> FlattenIndexed<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator;
+
+    /// Returns the mean of the elements, or `None` if there are none.
+    ///
+    /// The sum and count are kept in one pass, so this works on iterators that can only be
+    /// walked once. Any item type that converts losslessly to `f64` (like `i32` or `u32`,
+    /// but not `i64`) works.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let nested = vec![vec![1, 2], vec![3, 4]];
+    /// assert_eq!(nested.into_iter().our_flatten().our_average(), Some(2.5));
+    /// ```
+    fn our_average(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>;
+
+    /// Collects an iterator of `Result`s into a collection of the `Ok` values, or returns
+    /// the first `Err`.
+    ///
+    /// Like collecting into `Result<C, E>`, elements after the first `Err` are not pulled
+    /// from the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let parsed: Result<Vec<i32>, _> = ["1", "2"]
+    ///     .into_iter()
+    ///     .map(str::parse::<i32>)
+    ///     .our_collect_result();
+    /// assert_eq!(parsed, Ok(vec![1, 2]));
+    /// ```
+    fn our_collect_result<V, E, C>(self) -> Result<C, E>
+    where
+        Self: Sized + Iterator<Item = Result<V, E>>,
+        C: Default + Extend<V>;
+
+    /// Creates an iterator that can look at the next element, and change it, before
+    /// yielding it.
+    ///
+    /// Peeking pulls the element from the underlying iterator early and holds on to it,
+    /// which is often what a hand-written parser needs to decide what comes next.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let mut tokens = ["let", "x"].into_iter().map(String::from).our_peekable();
+    /// if tokens.peek().is_some_and(|t| t == "let") {
+    ///     tokens.peek_mut().unwrap().make_ascii_uppercase();
+    /// }
+    /// assert_eq!(tokens.next().as_deref(), Some("LET"));
+    /// ```
+    fn our_peekable(self) -> Peekable<Self>
+    where
+        Self: Sized;
+
+    /// Creates an iterator over the values inside the `Some`s, skipping the `None`s.
+    ///
+    /// This is what `flatten` does to an iterator of `Option`s, but without going through
+    /// `Option`'s `IntoIterator`. It can't know how many `None`s are left, so the lower
+    /// bound of its `size_hint` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let firsts: Vec<_> = ["ab", "", "c"].into_iter().map(|s| s.chars().next()).our_flatten_option().collect();
+    /// assert_eq!(firsts, vec!['a', 'c']);
+    /// ```
+    fn our_flatten_option<V>(self) -> FlattenOption<Self>
+    where
+        Self: Sized + Iterator<Item = Option<V>>;
+
+    /// Creates an iterator over batches of `n` consecutive elements, collected into
+    /// `Vec`s, leaving out a trailing batch that would be shorter.
+    ///
+    /// Like `slice::chunks_exact`, the leftover elements can be read afterwards with
+    /// [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let mut pairs = "abcde".chars().our_chunks_exact(2);
+    /// assert_eq!(pairs.by_ref().collect::<Vec<_>>(), vec![vec!['a', 'b'], vec!['c', 'd']]);
+    /// assert_eq!(pairs.remainder(), ['e']);
+    /// ```
+    fn our_chunks_exact(self, n: usize) -> ChunksExact<Self>
+    where
+        Self: Sized;
+
+    /// Turns an iterator of rows into a `Vec` of columns.
+    ///
+    /// The elements are moved into the columns, so they don't need to be `Clone`. No rows,
+    /// or rows with no elements, give no columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows don't all have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// assert_eq!(rows.into_iter().our_transpose(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    fn our_transpose<V>(self) -> Vec<Vec<V>>
+    where
+        Self: Sized + Iterator<Item = Vec<V>>;
+
+    /// Returns the largest element of each window of `window` consecutive elements.
+    ///
+    /// A deque holds the indices of the elements that could still be the maximum of a
+    /// later window, in decreasing order: each new element first evicts the smaller ones
+    /// behind it. Every element is pushed and popped at most once, so this takes O(n)
+    /// time however large the window is, and clones one element per window. Fewer than
+    /// `window` elements give no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iterators::IteratorExt;
+    ///
+    /// let maxima = [1, 3, -1, -3, 5, 3, 6, 7].into_iter().our_sliding_max(3);
+    /// assert_eq!(maxima, vec![3, 3, 5, 5, 6, 7]);
+    /// ```
+    fn our_sliding_max(self, window: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone;
+}
+
+impl<T> IteratorExt for T
+where
+    T: Iterator,
+{
+    fn our_flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        flatten(self)
+    }
+
+    fn our_cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        Cycle::new(self)
+    }
+
+    fn our_unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        Self: Sized + Iterator<Item = (A, B)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut left = FromA::default();
+        let mut right = FromB::default();
+        for (a, b) in self {
+            left.extend(Some(a));
+            right.extend(Some(b));
+        }
+        (left, right)
+    }
+
+    fn our_try_flatten<II, E>(self) -> TryFlatten<Self, II, E>
+    where
+        Self: Sized,
+        Self::Item: Into<Result<II, E>>,
+        II: IntoIterator,
+    {
+        TryFlatten::new(self)
+    }
+
+    fn our_enumerate_from(self, start: usize) -> EnumerateFrom<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateFrom::new(self, start)
+    }
+
+    fn our_flatten_while<P>(self, pred: P) -> FlattenWhile<Self, P>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+        P: FnMut(&<Self::Item as IntoIterator>::Item) -> bool,
+    {
+        FlattenWhile::new(self, pred)
+    }
+
+    fn our_sum_checked(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedArith,
+    {
+        self.try_fold(CheckedArith::ZERO, CheckedArith::checked_add)
+    }
+
+    fn our_product_checked(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CheckedArith,
+    {
+        self.try_fold(CheckedArith::ONE, CheckedArith::checked_mul)
+    }
+
+    fn our_arg_max_by_key<B, F>(self, mut f: F) -> Option<(usize, Self::Item)>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+    {
+        let mut best: Option<(B, usize, Self::Item)> = None;
+        for (index, item) in self.enumerate() {
+            let key = f(&item);
+            match best {
+                // Only a strictly smaller key keeps the current best, so ties go to the later element.
+                Some((ref best_key, _, _)) if key < *best_key => {}
+                _ => best = Some((key, index, item)),
+            }
+        }
+        best.map(|(_, index, item)| (index, item))
+    }
+
+    fn our_reduce<F>(mut self, mut f: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let mut acc = self.next()?;
+        for item in self {
+            acc = f(acc, item);
+        }
+        Some(acc)
+    }
+
+    fn our_try_fold<B, E, F>(self, init: B, mut f: F) -> Result<B, E>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> Result<B, E>,
+    {
+        let mut acc = init;
+        for item in self {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    fn our_by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    fn our_take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    fn our_collect_string(self) -> String
+    where
+        Self: Sized,
+        Self::Item: AsRef<str>,
+    {
+        let mut out = String::new();
+        for piece in self {
+            out.push_str(piece.as_ref());
+        }
+        out
+    }
+
+    fn our_map_while<B, P>(self, predicate: P) -> MapWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> Option<B>,
+    {
+        MapWhile::new(self, predicate)
+    }
+
+    fn our_nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            self.next()?;
+        }
+        self.next()
+    }
+
+    fn our_chain<U>(self, other: U) -> Chain<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator<Item = Self::Item>,
+    {
+        Chain::new(self, other.into_iter())
+    }
+
+    fn our_count_where<P>(self, mut predicate: P) -> usize
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let mut count = 0;
+        for item in self {
+            if predicate(&item) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn our_rev_collected(self) -> std::vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.reverse();
+        items.into_iter()
+    }
+
+    fn our_all_equal(mut self) -> bool
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        match self.next() {
+            Some(first) => self.all(|item| item == first),
+            None => true,
+        }
+    }
+
+    fn our_find_map<B, F>(&mut self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        for item in self {
+            if let Some(mapped) = f(item) {
+                return Some(mapped);
+            }
+        }
+        None
+    }
+
+    fn our_position<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(Self::Item) -> bool,
+    {
+        for (index, item) in self.enumerate() {
+            if predicate(item) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn our_min_max(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        let (mut min, mut max) = (first.clone(), first);
+        while let Some(a) = self.next() {
+            // Order the pair first, so each element is compared against only one of `min`
+            // and `max`.
+            let (small, large) = match self.next() {
+                Some(b) if b < a => (b, a),
+                Some(b) => (a, b),
+                None => (a.clone(), a),
+            };
+            if small < min {
+                min = small;
+            }
+            if large >= max {
+                max = large;
+            }
+        }
+        Some((min, max))
+    }
+
+    fn our_sum_by_key<B, F>(self, f: F) -> B
+    where
+        Self: Sized,
+        B: std::iter::Sum,
+        F: FnMut(Self::Item) -> B,
+    {
+        B::sum(self.map(f))
+    }
+
+    fn our_intersperse_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    where
+        Self: Sized,
+        G: FnMut() -> Self::Item,
+    {
+        IntersperseWith::new(self, separator)
+    }
+
+    fn our_filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        FilterMap::new(self, f)
+    }
+
+    fn our_tuple_windows(self) -> TupleWindows<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        TupleWindows::new(self)
+    }
+
+    fn our_last_n(self, n: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut ring = Vec::with_capacity(n.min(self.size_hint().0));
+        // Once the ring is full, `oldest` is the slot the next element overwrites.
+        let mut oldest = 0;
+        for item in self {
+            if ring.len() < n {
+                ring.push(item);
+            } else {
+                ring[oldest] = item;
+                oldest = (oldest + 1) % n;
+            }
+        }
+        ring.rotate_left(oldest);
+        ring
+    }
+
+    fn our_enumerate_fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, usize, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for (index, item) in self.enumerate() {
+            acc = f(acc, index, item);
+        }
+        acc
+    }
+
+    fn our_dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        DedupByKey::new(self, key)
+    }
+
+    fn our_flatten_indexed(self) -> FlattenIndexed<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        FlattenIndexed::new(self)
+    }
+
+    fn our_average(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let mut sum = 0.0;
+        let mut count = 0_usize;
+        for item in self {
+            sum += item.into();
+            count += 1;
+        }
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    fn our_collect_result<V, E, C>(self) -> Result<C, E>
+    where
+        Self: Sized + Iterator<Item = Result<V, E>>,
+        C: Default + Extend<V>,
+    {
+        let mut collection = C::default();
+        for item in self {
+            collection.extend(Some(item?));
+        }
+        Ok(collection)
+    }
+
+    fn our_peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable::new(self)
+    }
 
-/// An extension trait for iterators that provides the `our_flatten` method.
-pub trait IteratorExt: Iterator {
-    /// Flattens an iterator of iterables into a single iterator.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use your_crate_name::IteratorExt; // Replace `your_crate_name` with your crate's name
-    ///
-    /// let nested = vec![vec![1, 2], vec![3, 4]];
-    /// let flat_iter = nested.into_iter().our_flatten();
-    ///
-    /// let collected: Vec<_> = flat_iter.collect();
-    /// assert_eq!(collected, vec![1, 2, 3, 4]);
-    /// ```
-    fn our_flatten(self) -> Flatten<Self>
+    fn our_flatten_option<V>(self) -> FlattenOption<Self>
+    where
+        Self: Sized + Iterator<Item = Option<V>>,
+    {
+        FlattenOption::new(self)
+    }
+
+    fn our_chunks_exact(self, n: usize) -> ChunksExact<Self>
     where
         Self: Sized,
-        Self::Item: IntoIterator;
-}
+    {
+        ChunksExact::new(self, n)
+    }
 
-impl<T> IteratorExt for T
-where
-    T: Iterator,
-{
-    fn our_flatten(self) -> Flatten<Self>
+    fn our_transpose<V>(self) -> Vec<Vec<V>>
+    where
+        Self: Sized + Iterator<Item = Vec<V>>,
+    {
+        let mut rows = self.enumerate();
+        let Some((_, first)) = rows.next() else {
+            return Vec::new();
+        };
+        let width = first.len();
+        let mut columns: Vec<Vec<V>> = first.into_iter().map(|item| vec![item]).collect();
+        for (index, row) in rows {
+            assert_eq!(
+                row.len(),
+                width,
+                "our_transpose needs rows of equal length, but row {index} has {} elements instead of {width}",
+                row.len()
+            );
+            for (column, item) in columns.iter_mut().zip(row) {
+                column.push(item);
+            }
+        }
+        columns
+    }
+
+    fn our_sliding_max(self, window: usize) -> Vec<Self::Item>
     where
         Self: Sized,
-        Self::Item: IntoIterator,
+        Self::Item: Ord + Clone,
     {
-        flatten(self)
+        assert!(window > 0, "our_sliding_max needs a window of at least 1");
+        let mut maxima = Vec::new();
+        // Decreasing from front to back; the front is the current window's maximum.
+        let mut candidates: VecDeque<(usize, Self::Item)> = VecDeque::new();
+        for (index, item) in self.enumerate() {
+            while candidates.back().is_some_and(|(_, back)| *back <= item) {
+                candidates.pop_back();
+            }
+            candidates.push_back((index, item));
+            if candidates
+                .front()
+                .is_some_and(|&(front, _)| front + window <= index)
+            {
+                candidates.pop_front();
+            }
+            if index + 1 >= window {
+                maxima.push(candidates[0].1.clone());
+            }
+        }
+        maxima
     }
 }
 
@@ -130,6 +1276,24 @@ where
     Flatten::new(iter.into_iter())
 }
 
+/// Flattens a slice of `Vec`s by reference, leaving the input untouched.
+///
+/// This is [`flatten`] over `slices.iter()`: each `&Vec<T>` is turned into its `iter()`
+/// rather than its `into_iter()`, so the items are `&T`.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::flatten_ref;
+///
+/// let nested = vec![vec![1, 2], vec![3]];
+/// assert_eq!(flatten_ref(&nested).collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// assert_eq!(nested.len(), 2);
+/// ```
+pub fn flatten_ref<T>(slices: &[Vec<T>]) -> Flatten<std::slice::Iter<'_, Vec<T>>> {
+    Flatten::new(slices.iter())
+}
+
 /// An iterator that flattens an iterator of iterators into a single iterator.
 ///
 /// This struct is created by the [`flatten`] function or the [`our_flatten`] method on [`IteratorExt`].
@@ -209,6 +1373,48 @@ where
     }
 }
 
+impl<O> FusedIterator for Flatten<O>
+where
+    O: FusedIterator,
+    O::Item: IntoIterator,
+    <O::Item as IntoIterator>::IntoIter: FusedIterator,
+{
+}
+
+impl<O> Clone for Flatten<O>
+where
+    O: Iterator + Clone,
+    O::Item: IntoIterator,
+    <O::Item as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Flatten {
+            outer: self.outer.clone(),
+            front_iter: self.front_iter.clone(),
+            back_iter: self.back_iter.clone(),
+        }
+    }
+}
+
+/// Iterating over `&Flatten` walks a snapshot of its current position, so the same view
+/// can be iterated several times without rebuilding it.
+///
+/// This only works when the outer iterator and the inner iterators are `Clone`, and each
+/// iteration clones them, so it's only cheap when they are (e.g. slice iterators).
+impl<O> IntoIterator for &Flatten<O>
+where
+    O: Iterator + Clone,
+    O::Item: IntoIterator,
+    <O::Item as IntoIterator>::IntoIter: Clone,
+{
+    type Item = <O::Item as IntoIterator>::Item;
+    type IntoIter = Flatten<O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,4 +1482,510 @@ mod tests {
     fn ext() {
         assert_eq!(vec![vec![0, 1]].into_iter().our_flatten().count(), 2);
     }
+
+    #[test]
+    fn unzip() {
+        let (numbers, letters): (Vec<_>, Vec<_>) = vec![(1, 'a'), (2, 'b')].into_iter().our_unzip();
+        assert_eq!(numbers, vec![1, 2]);
+        assert_eq!(letters, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn unzip_into_other_collections() {
+        let (numbers, letters): (std::collections::HashSet<_>, String) =
+            vec![(1, 'a'), (1, 'b')].into_iter().our_unzip();
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(letters, "ab");
+    }
+
+    #[test]
+    fn both_ends_ragged() {
+        let mut iter = flatten(vec![vec!["a"], vec!["b1", "b2", "b3"], vec![]]);
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next_back(), Some("b3"));
+        assert_eq!(iter.next(), Some("b1"));
+        assert_eq!(iter.next_back(), Some("b2"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn both_ends_meet_in_one_inner() {
+        let mut iter = flatten(vec![vec![], vec!["a1", "a2", "a3"], vec![]]);
+        assert_eq!(iter.next_back(), Some("a3"));
+        assert_eq!(iter.next(), Some("a1"));
+        assert_eq!(iter.next_back(), Some("a2"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn both_ends_every_order() {
+        // Drive every sequence of `next`/`next_back` calls over some ragged inputs and
+        // compare against a `VecDeque` popped from the matching end.
+        let inputs = vec![
+            vec![vec![1], vec![2, 3, 4], vec![]],
+            vec![vec![], vec![1, 2], vec![], vec![3], vec![4, 5, 6]],
+            vec![vec![1, 2, 3, 4], vec![5]],
+            vec![vec![], vec![], vec![1]],
+        ];
+        for input in inputs {
+            let len: usize = input.iter().map(Vec::len).sum();
+            for order in 0..1u32 << (len + 1) {
+                let mut iter = flatten(input.clone());
+                let mut model: std::collections::VecDeque<_> =
+                    input.iter().flatten().copied().collect();
+                for step in 0..=len {
+                    if order & (1 << step) == 0 {
+                        assert_eq!(iter.next(), model.pop_front(), "{input:?} {order:b}");
+                    } else {
+                        assert_eq!(iter.next_back(), model.pop_back(), "{input:?} {order:b}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iterate_by_ref_twice() {
+        let nested = vec![vec![1, 2], vec![], vec![3]];
+        let flat = flatten(&nested);
+        let first: Vec<_> = (&flat).into_iter().collect();
+        let second: Vec<_> = (&flat).into_iter().collect();
+        assert_eq!(first, vec![&1, &2, &3]);
+        assert_eq!(first, second);
+
+        let mut sum = 0;
+        for x in &flat {
+            sum += x;
+        }
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn iterate_by_ref_snapshots_position() {
+        let nested = vec![vec!["a1", "a2"], vec!["b1", "b2"]];
+        let mut flat = flatten(&nested);
+        assert_eq!(flat.next(), Some(&"a1"));
+        assert_eq!(flat.next_back(), Some(&"b2"));
+        assert_eq!((&flat).into_iter().collect::<Vec<_>>(), vec![&"a2", &"b1"]);
+        assert_eq!(flat.collect::<Vec<_>>(), vec![&"a2", &"b1"]);
+    }
+
+    #[test]
+    fn sum_checked() {
+        assert_eq!((1..=10).our_sum_checked(), Some(55));
+        assert_eq!(std::iter::empty::<u32>().our_sum_checked(), Some(0));
+        assert_eq!([200u8, 55].into_iter().our_sum_checked(), Some(255));
+        assert_eq!([200u8, 56].into_iter().our_sum_checked(), None);
+        assert_eq!([i64::MIN, -1].into_iter().our_sum_checked(), None);
+    }
+
+    #[test]
+    fn product_checked() {
+        assert_eq!((1..=5).our_product_checked(), Some(120));
+        assert_eq!(std::iter::empty::<u32>().our_product_checked(), Some(1));
+        assert_eq!([16u8, 16].into_iter().our_product_checked(), None);
+        assert_eq!([0u8, 16, 16].into_iter().our_product_checked(), Some(0));
+    }
+
+    #[test]
+    fn arg_max_by_key() {
+        assert_eq!(
+            [3, 1, 4, 1, 5, 2].into_iter().our_arg_max_by_key(|&x| x),
+            Some((4, 5))
+        );
+        assert_eq!(std::iter::empty::<i32>().our_arg_max_by_key(|&x| x), None);
+    }
+
+    #[test]
+    fn arg_max_by_key_prefers_last_tie() {
+        let words = ["bb", "a", "cc", "d"];
+        assert_eq!(
+            words.into_iter().our_arg_max_by_key(|w| w.len()),
+            Some((2, "cc"))
+        );
+        assert_eq!(
+            words.into_iter().our_arg_max_by_key(|w| w.len()),
+            words.into_iter().enumerate().max_by_key(|(_, w)| w.len())
+        );
+    }
+
+    #[test]
+    fn reduce() {
+        assert_eq!([1, 2, 3, 4].into_iter().our_reduce(|a, b| a + b), Some(10));
+        assert_eq!([7].into_iter().our_reduce(|a, b| a + b), Some(7));
+        assert_eq!(std::iter::empty::<i32>().our_reduce(|a, b| a + b), None);
+    }
+
+    #[test]
+    fn try_fold_all_ok() {
+        let result: Result<i32, String> =
+            [1, 2, 3].into_iter().our_try_fold(0, |acc, x| Ok(acc + x));
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn try_fold_stops_at_first_err() {
+        let mut seen = 0;
+        let mut iter = [1, 2, 3, 4, 5].into_iter();
+        let result = iter.by_ref().our_try_fold(0, |acc, x| {
+            seen += 1;
+            if x == 3 {
+                Err(format!("bad element {x}"))
+            } else {
+                Ok(acc + x)
+            }
+        });
+        assert_eq!(result, Err(String::from("bad element 3")));
+        assert_eq!(seen, 3);
+        // The remaining elements are still in the iterator.
+        assert_eq!(iter.collect::<Vec<_>>(), vec![4, 5]);
+    }
+
+    #[test]
+    fn collect_string_from_split() {
+        use lifetimes::StrSplit;
+
+        assert_eq!(StrSplit::new("a b c", ' ').our_collect_string(), "abc");
+        assert_eq!(
+            vec![String::from("x"), String::from("yz")]
+                .into_iter()
+                .our_collect_string(),
+            "xyz"
+        );
+        assert_eq!(std::iter::empty::<&str>().our_collect_string(), "");
+    }
+
+    #[test]
+    fn nth_leaves_position_after_element() {
+        use lifetimes::StrSplit;
+
+        let mut split = StrSplit::new("a b c d e", ' ');
+        assert_eq!(split.our_nth(2), Some("c"));
+        assert_eq!(split.collect::<Vec<_>>(), vec!["d", "e"]);
+
+        let mut iter = [1, 2].into_iter();
+        assert_eq!(iter.our_nth(5), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn count_where() {
+        assert_eq!((0..10).our_count_where(|x| x % 2 == 0), 5);
+        assert_eq!((0..10).our_count_where(|_| false), 0);
+        assert_eq!(std::iter::empty::<i32>().our_count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn rev_collected_forward_only() {
+        // `from_fn` is never double-ended.
+        let mut n = 0;
+        let forward_only = std::iter::from_fn(|| {
+            n += 1;
+            (n <= 4).then_some(n)
+        });
+        let mut reversed = forward_only.our_rev_collected();
+        assert_eq!(reversed.len(), 4);
+        assert_eq!(reversed.next(), Some(4));
+        assert_eq!(reversed.collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        assert_eq!(std::iter::empty::<i32>().our_rev_collected().next(), None);
+    }
+
+    #[test]
+    fn all_equal() {
+        assert!([3, 3, 3].into_iter().our_all_equal());
+        assert!([3].into_iter().our_all_equal());
+        assert!(![3, 3, 4, 3].into_iter().our_all_equal());
+        assert!(std::iter::empty::<i32>().our_all_equal());
+        assert!(![f64::NAN].into_iter().chain([f64::NAN]).our_all_equal());
+    }
+
+    #[test]
+    fn find_map_stops_early() {
+        let mut iter = ["a", "12", "b", "3"].into_iter();
+        assert_eq!(iter.our_find_map(|s| s.parse::<i32>().ok()), Some(12));
+        assert_eq!(iter.next(), Some("b"));
+        assert_eq!(iter.our_find_map(|s| s.parse::<i32>().ok()), Some(3));
+        assert_eq!(iter.our_find_map(|s| s.parse::<i32>().ok()), None);
+    }
+
+    #[test]
+    fn position() {
+        let mut iter = 0..10;
+        assert_eq!(iter.our_position(|x| x > 3), Some(4));
+        assert_eq!(iter.next(), Some(5));
+        // Indices count from where the iterator is now.
+        assert_eq!(iter.our_position(|x| x == 7), Some(1));
+        assert_eq!(iter.our_position(|x| x == 0), None);
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(std::iter::empty::<i32>().our_min_max(), None);
+        assert_eq!([7].into_iter().our_min_max(), Some((7, 7)));
+        assert_eq!(
+            [3, 1, 4, 1, 5, 9, 2, 6].into_iter().our_min_max(),
+            Some((1, 9))
+        );
+        assert_eq!([2, 1].into_iter().our_min_max(), Some((1, 2)));
+        assert_eq!([1, 2, 0].into_iter().our_min_max(), Some((0, 2)));
+    }
+
+    #[test]
+    fn min_max_ties_match_min_and_max() {
+        for items in [&[1, 1][..], &[2, 1, 2, 1], &[5, 3, 5, 3, 4], &[0, 0, 0]] {
+            let tied = || items.iter().enumerate().map(|(i, &x)| Tied(x, i));
+            let (min, max) = tied().our_min_max().unwrap();
+            assert_eq!(min.1, tied().min().unwrap().1, "{items:?}");
+            assert_eq!(max.1, tied().max().unwrap().1, "{items:?}");
+        }
+    }
+
+    /// Orders by the first field only, so equal elements can still be told apart.
+    #[derive(Clone, Debug)]
+    struct Tied(i32, usize);
+
+    impl PartialEq for Tied {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Tied {}
+
+    impl PartialOrd for Tied {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tied {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn sum_by_key() {
+        assert_eq!(["a", "bb", "ccc"].into_iter().our_sum_by_key(str::len), 6);
+        assert_eq!(
+            std::iter::empty::<&str>().our_sum_by_key(|s| s.len() as u64),
+            0
+        );
+    }
+
+    #[test]
+    fn flatten_ref_borrows() {
+        let nested = vec![
+            vec![String::from("a"), String::from("b")],
+            vec![],
+            vec![String::from("c")],
+        ];
+        let flat: Vec<&String> = flatten_ref(&nested).collect();
+        assert_eq!(flat, ["a", "b", "c"]);
+        assert!(std::ptr::eq(flat[2], &nested[2][0]));
+        assert_eq!(
+            flatten_ref(&nested).next_back().map(String::as_str),
+            Some("c")
+        );
+        // Still usable: nothing was moved out.
+        assert_eq!(nested[0], ["a", "b"]);
+        assert_eq!(flatten_ref::<i32>(&[]).next(), None);
+    }
+
+    /// Drains `iter`, then checks that `next` keeps returning `None` well past the end.
+    ///
+    /// The `FusedIterator` bound also checks at compile time that the impl exists.
+    fn assert_stays_exhausted<I: FusedIterator>(mut iter: I) {
+        while iter.next().is_some() {}
+        for _ in 0..8 {
+            assert!(iter.next().is_none());
+        }
+    }
+
+    /// An iterator that isn't fused: it alternates between `Some` and `None` forever.
+    struct Flaky {
+        calls: u32,
+    }
+
+    impl Iterator for Flaky {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.calls += 1;
+            (self.calls % 2 == 1).then_some(self.calls)
+        }
+    }
+
+    fn flaky() -> Flaky {
+        Flaky { calls: 0 }
+    }
+
+    #[test]
+    fn flaky_is_not_fused() {
+        let mut iter = flaky();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn fused_over_unfused_iterators() {
+        assert_stays_exhausted(flaky().our_take(5));
+        assert_stays_exhausted(flaky().our_chain(flaky()));
+        assert_stays_exhausted(flaky().our_chunks_exact(2));
+        assert_stays_exhausted(flaky().our_map_while(Some));
+        assert_stays_exhausted(flaky().map(|n| vec![n]).our_flatten_while(|_| true));
+        assert_stays_exhausted(flaky().map(|n| vec![n]).our_flatten_while(|&n| n < 1));
+    }
+
+    #[test]
+    fn fused_generators() {
+        assert_stays_exhausted(our_successors(Some(1), |&n: &u32| n.checked_sub(1)));
+        let tree = Tree::Branch(vec![Tree::Leaf(1), Tree::Branch(vec![])]);
+        assert_stays_exhausted(tree.iter());
+    }
+
+    #[test]
+    fn fused_over_fused_iterators() {
+        assert_stays_exhausted(vec![vec![1], vec![2, 3]].into_iter().our_flatten());
+        assert_stays_exhausted(flatten_vecs(vec![vec![1], vec![2, 3]]));
+        assert_stays_exhausted([1, 2].into_iter().our_enumerate_from(7));
+        assert_stays_exhausted([1, 2].into_iter().our_intersperse_with(|| 0));
+        assert_stays_exhausted([1, 2, 3].into_iter().our_tuple_windows());
+        assert_stays_exhausted([1, 1, 2].into_iter().our_dedup_by_key(|&n| n));
+        assert_stays_exhausted([1, 2].into_iter().our_peekable());
+        assert_stays_exhausted([Some(1), None].into_iter().our_flatten_option());
+        assert_stays_exhausted(
+            vec![vec![1], vec![], vec![2]]
+                .into_iter()
+                .our_flatten_indexed(),
+        );
+        assert_stays_exhausted(
+            ["1", "x"]
+                .into_iter()
+                .our_filter_map(|s| s.parse::<i32>().ok()),
+        );
+        assert_stays_exhausted(
+            vec![Ok(vec![1]), Err("bad"), Ok(vec![2])]
+                .into_iter()
+                .our_try_flatten(),
+        );
+        assert_stays_exhausted(std::iter::empty::<Result<Vec<i32>, ()>>().our_try_flatten());
+    }
+
+    #[test]
+    fn last_n() {
+        let input = [1, 2, 3, 4, 5];
+        assert_eq!(input.into_iter().our_last_n(2), vec![4, 5]);
+        assert_eq!(input.into_iter().our_last_n(5), vec![1, 2, 3, 4, 5]);
+        assert_eq!(input.into_iter().our_last_n(8), vec![1, 2, 3, 4, 5]);
+        assert_eq!(input.into_iter().our_last_n(0), Vec::<i32>::new());
+        // Non-`Clone` items, wrapping around the ring more than once.
+        let words = (0..10).map(|i| i.to_string()).our_last_n(3);
+        assert_eq!(words, ["7", "8", "9"]);
+    }
+
+    #[test]
+    fn enumerate_fold() {
+        let weighted = [10, 20, 30]
+            .into_iter()
+            .our_enumerate_fold(0, |acc, i, x| acc + i * x);
+        // 0 * 10 + 1 * 20 + 2 * 30
+        assert_eq!(weighted, 80);
+
+        let indices = ["a", "b"]
+            .into_iter()
+            .our_enumerate_fold(Vec::new(), |mut acc, i, s| {
+                acc.push(format!("{i}{s}"));
+                acc
+            });
+        assert_eq!(indices, ["0a", "1b"]);
+        assert_eq!(
+            std::iter::empty::<i32>().our_enumerate_fold(7, |_, _, _| 0),
+            7
+        );
+    }
+
+    #[test]
+    fn average() {
+        assert_eq!([1, 2, 3, 4].into_iter().our_average(), Some(2.5));
+        assert_eq!([0.5_f32, 1.5].into_iter().our_average(), Some(1.0));
+        assert_eq!([7_u8].into_iter().our_average(), Some(7.0));
+        assert_eq!(std::iter::empty::<i32>().our_average(), None);
+    }
+
+    #[test]
+    fn collect_result() {
+        let all_ok: Result<Vec<_>, &str> = [Ok(1), Ok(2), Ok(3)].into_iter().our_collect_result();
+        assert_eq!(all_ok, Ok(vec![1, 2, 3]));
+
+        let mut iter = [Ok(1), Err("bad"), Ok(3)].into_iter();
+        let failed: Result<Vec<_>, _> = iter.by_ref().our_collect_result();
+        assert_eq!(failed, Err("bad"));
+        // Stopped right after the error.
+        assert_eq!(iter.next(), Some(Ok(3)));
+
+        let set: Result<std::collections::BTreeSet<_>, ()> =
+            [Ok('b'), Ok('a'), Ok('b')].into_iter().our_collect_result();
+        assert_eq!(set.unwrap().into_iter().collect::<String>(), "ab");
+    }
+
+    #[test]
+    fn transpose() {
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(
+            rows.into_iter().our_transpose(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        let strings = vec![vec![String::from("a")], vec![String::from("b")]];
+        assert_eq!(strings.into_iter().our_transpose(), vec![vec!["a", "b"]]);
+        assert!(std::iter::empty::<Vec<i32>>().our_transpose().is_empty());
+        assert!(vec![Vec::<i32>::new(); 3]
+            .into_iter()
+            .our_transpose()
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 has 1 elements instead of 2")]
+    fn transpose_ragged() {
+        vec![vec![1, 2], vec![3, 4], vec![5]]
+            .into_iter()
+            .our_transpose();
+    }
+
+    #[test]
+    fn sliding_max() {
+        let values = [1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(
+            values.into_iter().our_sliding_max(3),
+            vec![3, 3, 5, 5, 6, 7]
+        );
+        assert_eq!(values.into_iter().our_sliding_max(1), values);
+        assert_eq!(values.into_iter().our_sliding_max(8), vec![7]);
+        assert!(values.into_iter().our_sliding_max(9).is_empty());
+        assert_eq!(
+            [5, 4, 3, 2, 1].into_iter().our_sliding_max(2),
+            vec![5, 4, 3, 2]
+        );
+
+        // Matches the brute force on every window size.
+        let values = [2, 7, 7, 1, 8, 2, 8, 0, 3];
+        for window in 1..=values.len() {
+            let expected: Vec<_> = values
+                .windows(window)
+                .map(|w| *w.iter().max().unwrap())
+                .collect();
+            assert_eq!(values.into_iter().our_sliding_max(window), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window of at least 1")]
+    fn sliding_max_zero_window() {
+        [1].into_iter().our_sliding_max(0);
+    }
 }
@@ -0,0 +1,83 @@
+use std::iter::FusedIterator;
+
+/// An iterator that maps elements until the closure first returns `None`.
+///
+/// This struct is created by the [`our_map_while`] method on [`IteratorExt`].
+///
+/// [`our_map_while`]: crate::IteratorExt::our_map_while
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct MapWhile<I, P> {
+    iter: I,
+    predicate: P,
+    /// Set once `predicate` has returned `None` (or `iter` has run out).
+    done: bool,
+}
+
+impl<I, P> MapWhile<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        MapWhile {
+            iter,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+impl<B, I, P> Iterator for MapWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mapped = self.iter.next().and_then(&mut self.predicate);
+        self.done = mapped.is_none();
+        mapped
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            // Any element might be the one that stops us.
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<B, I, P> FusedIterator for MapWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(I::Item) -> Option<B>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn parses_prefix() {
+        let numbers: Vec<i32> = ["1", "2", "x", "3"]
+            .into_iter()
+            .our_map_while(|s| s.parse().ok())
+            .collect();
+        assert_eq!(numbers, vec![1, 2]);
+    }
+
+    #[test]
+    fn fused_after_none() {
+        let mut iter = [1, -1, 2]
+            .into_iter()
+            .our_map_while(|x: i32| u32::try_from(x).ok());
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        // `2` would map fine, but the adapter has already stopped.
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
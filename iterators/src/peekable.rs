@@ -0,0 +1,113 @@
+use std::iter::FusedIterator;
+
+/// An iterator with one element of lookahead, which can be inspected or changed before
+/// it is yielded.
+///
+/// This struct is created by the [`our_peekable`] method on [`IteratorExt`].
+///
+/// [`our_peekable`]: crate::IteratorExt::our_peekable
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct Peekable<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    /// The element pulled early by a peek. `Some(None)` remembers that the underlying
+    /// iterator ran out, so peeking again doesn't call its `next` a second time.
+    peeked: Option<Option<I::Item>>,
+}
+
+impl<I> Peekable<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Peekable { iter, peeked: None }
+    }
+
+    /// Returns a reference to the next element without advancing past it.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+
+    /// Returns a mutable reference to the next element without advancing past it.
+    ///
+    /// Changes made through it are what the following `next` returns.
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_mut()
+    }
+}
+
+impl<I> Iterator for Peekable<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|n| n.checked_add(peeked)),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for Peekable<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for Peekable<I> where I: FusedIterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn peek_does_not_advance() {
+        let mut iter = [1, 2].into_iter().our_peekable();
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn peek_mut_changes_next() {
+        let mut iter = [1, 2, 3].into_iter().our_peekable();
+        if let Some(first) = iter.peek_mut() {
+            *first *= 10;
+        }
+        assert_eq!(iter.peek(), Some(&10));
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn size_hint_counts_peeked() {
+        let mut iter = [1, 2, 3].into_iter().our_peekable();
+        iter.peek();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        iter.next();
+        iter.peek();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,72 @@
+use std::iter::FusedIterator;
+
+/// Creates an iterator that starts at `first` and computes each next element from the
+/// previous one with `succ`, stopping when `succ` returns `None`.
+///
+/// Mirrors `std::iter::successors`.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::our_successors;
+///
+/// let tens: Vec<_> = our_successors(Some(1u32), |&n| n.checked_mul(10)).take(3).collect();
+/// assert_eq!(tens, vec![1, 10, 100]);
+/// ```
+pub fn our_successors<T, F>(first: Option<T>, succ: F) -> Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    Successors { next: first, succ }
+}
+
+/// An iterator where each element is computed from the one before it.
+///
+/// This struct is created by the [`our_successors`] function.
+pub struct Successors<T, F> {
+    /// The element to yield next; `None` once `succ` has given up.
+    next: Option<T>,
+    succ: F,
+}
+
+impl<T, F> Iterator for Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.next.take()?;
+        self.next = (self.succ)(&item);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<T, F> FusedIterator for Successors<T, F> where F: FnMut(&T) -> Option<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powers_of_two() {
+        let powers: Vec<u32> =
+            our_successors(Some(1), |&n| Some(n * 2).filter(|&n| n <= 100)).collect();
+        assert_eq!(powers, vec![1, 2, 4, 8, 16, 32, 64]);
+    }
+
+    #[test]
+    fn none_first_is_empty() {
+        let mut iter = our_successors(None, |&n: &i32| Some(n + 1));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+}
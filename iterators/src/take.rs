@@ -0,0 +1,131 @@
+use std::iter::FusedIterator;
+
+/// An iterator that yields at most a fixed number of elements.
+///
+/// Once it has returned `None` it keeps returning `None`, even if the underlying iterator
+/// would have produced more.
+///
+/// This struct is created by the [`our_take`] method on [`IteratorExt`].
+///
+/// [`our_take`]: crate::IteratorExt::our_take
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct Take<I> {
+    iter: I,
+    /// How many more elements may be yielded.
+    n: usize,
+}
+
+impl<I> Take<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Take { iter, n }
+    }
+}
+
+impl<I> Iterator for Take<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            // Don't pull (and lose) an element from the underlying iterator.
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.n -= 1;
+                Some(item)
+            }
+            None => {
+                // Stay exhausted rather than resuming an iterator that isn't fused.
+                self.n = 0;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let upper = match upper {
+            Some(upper) => upper.min(self.n),
+            None => self.n,
+        };
+        (lower.min(self.n), Some(upper))
+    }
+}
+
+impl<I> ExactSizeIterator for Take<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for Take<I> where I: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn resume_through_by_ref() {
+        let mut iter = [1, 2, 3, 4, 5].into_iter();
+        let head: Vec<_> = iter.our_by_ref().our_take(2).collect();
+        assert_eq!(head, vec![1, 2]);
+        // Nothing past the taken elements was consumed.
+        assert_eq!(iter.collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn fewer_exact_and_more_than_available() {
+        assert_eq!(
+            [1, 2, 3].into_iter().our_take(2).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            [1, 2, 3].into_iter().our_take(3).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            [1, 2, 3].into_iter().our_take(5).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn size_hint_caps_at_n() {
+        let mut iter = [1, 2, 3].into_iter().our_take(2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        assert_eq!([1, 2].into_iter().our_take(5).len(), 2);
+        assert_eq!((0..).our_take(4).size_hint(), (4, Some(4)));
+        assert_eq!(
+            (0..10).filter(|_| true).our_take(4).size_hint(),
+            (0, Some(4))
+        );
+    }
+
+    #[test]
+    fn fused_after_underlying_ends() {
+        // Yields `None` on every other call, so it isn't fused on its own.
+        let mut calls = 0;
+        let flaky = std::iter::from_fn(|| {
+            calls += 1;
+            (calls % 2 == 0).then_some(calls)
+        });
+        let mut iter = flaky.our_take(3);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn take_zero_pulls_nothing() {
+        let mut iter = [1, 2].into_iter();
+        assert_eq!(iter.our_by_ref().our_take(0).next(), None);
+        assert_eq!(iter.next(), Some(1));
+    }
+}
@@ -0,0 +1,134 @@
+use std::iter::FusedIterator;
+use std::slice;
+
+/// A tree whose values live in its leaves, in the same shape as the nested `Vec`s that
+/// [`Flatten`](crate::Flatten) flattens one level of.
+///
+/// # Example
+///
+/// ```rust
+/// use iterators::Tree;
+///
+/// let tree = Tree::Branch(vec![Tree::Leaf(1), Tree::Branch(vec![Tree::Leaf(2)])]);
+/// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tree<T> {
+    Leaf(T),
+    Branch(Vec<Tree<T>>),
+}
+
+impl<T> Tree<T> {
+    /// Returns an iterator over the leaves, from left to right.
+    pub fn iter(&self) -> Leaves<'_, T> {
+        Leaves::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Tree<T> {
+    type Item = &'a T;
+    type IntoIter = Leaves<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the leaves of a [`Tree`], from left to right.
+///
+/// `Flatten` removes exactly one level of nesting and knows its item types statically, so
+/// it can't follow a tree of arbitrary depth. This keeps the same front-iterator idea, but
+/// with a stack of them: one per branch on the path to the current leaf.
+///
+/// This struct is created by the [`Tree::iter`] method.
+pub struct Leaves<'a, T> {
+    /// The root, until the first call to `next`.
+    root: Option<&'a Tree<T>>,
+    /// The unvisited children of every branch we are currently inside, innermost last.
+    stack: Vec<slice::Iter<'a, Tree<T>>>,
+}
+
+impl<'a, T> Leaves<'a, T> {
+    fn new(root: &'a Tree<T>) -> Self {
+        Leaves {
+            root: Some(root),
+            stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Leaves<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.root.take() {
+                Some(root) => root,
+                None => {
+                    let children = self.stack.last_mut()?;
+                    match children.next() {
+                        Some(child) => child,
+                        None => {
+                            // Every child of the innermost branch is visited; go back up.
+                            self.stack.pop();
+                            continue;
+                        }
+                    }
+                }
+            };
+            match node {
+                Tree::Leaf(value) => return Some(value),
+                Tree::Branch(children) => self.stack.push(children.iter()),
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Leaves<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Tree::{self, Branch, Leaf};
+    use crate::IteratorExt;
+
+    #[test]
+    fn three_levels_in_order() {
+        let tree = Branch(vec![
+            Leaf(1),
+            Branch(vec![Leaf(2), Branch(vec![Leaf(3), Leaf(4)]), Leaf(5)]),
+            Branch(vec![Branch(vec![Leaf(6)])]),
+            Leaf(7),
+        ]);
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn empty_branches_and_single_leaf() {
+        let tree: Tree<i32> = Branch(vec![Branch(vec![]), Branch(vec![Branch(vec![])])]);
+        assert_eq!(tree.iter().next(), None);
+
+        let leaf = Leaf("only");
+        assert_eq!((&leaf).into_iter().collect::<Vec<_>>(), vec![&"only"]);
+
+        let tree = Branch(vec![Branch(vec![]), Leaf(1), Branch(vec![]), Leaf(2)]);
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn matches_flatten_for_two_levels() {
+        let nested = vec![vec![1, 2], vec![], vec![3]];
+        let tree = Branch(
+            nested
+                .iter()
+                .map(|inner| Branch(inner.iter().copied().map(Leaf).collect()))
+                .collect(),
+        );
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<_>>(),
+            nested.into_iter().our_flatten().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// An iterator that flattens an iterator of `Result`s of iterables, stopping at the first `Err`.
+///
+/// This struct is created by the [`our_try_flatten`] method on [`IteratorExt`].
+///
+/// [`our_try_flatten`]: crate::IteratorExt::our_try_flatten
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct TryFlatten<O, II, E>
+where
+    II: IntoIterator,
+{
+    /// Set to `None` once an `Err` has been yielded, which fuses the iterator.
+    outer: Option<O>,
+    front_iter: Option<II::IntoIter>,
+    _error: PhantomData<E>,
+}
+
+impl<O, II, E> TryFlatten<O, II, E>
+where
+    II: IntoIterator,
+{
+    pub(crate) fn new(iter: O) -> Self {
+        TryFlatten {
+            outer: Some(iter),
+            front_iter: None,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<O, II, E> Iterator for TryFlatten<O, II, E>
+where
+    O: Iterator,
+    O::Item: Into<Result<II, E>>,
+    II: IntoIterator,
+{
+    type Item = Result<II::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut front_iter) = self.front_iter {
+                if let Some(item) = front_iter.next() {
+                    return Some(Ok(item));
+                }
+                self.front_iter = None;
+            }
+
+            match self.outer.as_mut()?.next()?.into() {
+                Ok(next_inner) => self.front_iter = Some(next_inner.into_iter()),
+                Err(e) => {
+                    self.outer = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+impl<O, II, E> FusedIterator for TryFlatten<O, II, E>
+where
+    O: FusedIterator,
+    O::Item: Into<Result<II, E>>,
+    II: IntoIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn all_ok() {
+        let nested: Vec<Result<Vec<i32>, &str>> = vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3])];
+        assert_eq!(
+            nested.into_iter().our_try_flatten().collect::<Vec<_>>(),
+            vec![Ok(1), Ok(2), Ok(3)]
+        );
+    }
+
+    #[test]
+    fn stops_after_err() {
+        let nested = vec![Ok(vec![1, 2]), Err("bad"), Ok(vec![3]), Err("worse")];
+        let mut iter = nested.into_iter().our_try_flatten();
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Err("bad")));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn collect_into_result() {
+        let nested: Vec<Result<Vec<i32>, &str>> = vec![Ok(vec![1]), Err("bad"), Ok(vec![2])];
+        let collected: Result<Vec<_>, _> = nested.into_iter().our_try_flatten().collect();
+        assert_eq!(collected, Err("bad"));
+    }
+}
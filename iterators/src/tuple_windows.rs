@@ -0,0 +1,108 @@
+use std::iter::FusedIterator;
+
+/// An iterator over overlapping pairs of consecutive elements.
+///
+/// This struct is created by the [`our_tuple_windows`] method on [`IteratorExt`].
+///
+/// [`our_tuple_windows`]: crate::IteratorExt::our_tuple_windows
+/// [`IteratorExt`]: crate::IteratorExt
+pub struct TupleWindows<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    /// The second element of the previous pair, which starts the next one.
+    last: Option<I::Item>,
+}
+
+impl<I> TupleWindows<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        TupleWindows { iter, last: None }
+    }
+}
+
+impl<I> Iterator for TupleWindows<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.last.take() {
+            Some(first) => first,
+            None => self.iter.next()?,
+        };
+        let second = self.iter.next()?;
+        // Only one clone per pair: the element shared with the next window.
+        self.last = Some(second.clone());
+        Some((first, second))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `n` elements (counting the held-over one) make `n - 1` pairs.
+        let held = usize::from(self.last.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        let pairs = |n: usize| n.saturating_add(held).saturating_sub(1);
+        (
+            pairs(lower),
+            upper
+                .and_then(|n| n.checked_add(held))
+                .map(|n| n.saturating_sub(1)),
+        )
+    }
+}
+
+impl<I> FusedIterator for TupleWindows<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn consecutive_pairs() {
+        assert_eq!(
+            [1, 2, 3]
+                .into_iter()
+                .our_tuple_windows()
+                .collect::<Vec<_>>(),
+            vec![(1, 2), (2, 3)]
+        );
+        assert_eq!(
+            ["a", "b"]
+                .into_iter()
+                .our_tuple_windows()
+                .collect::<Vec<_>>(),
+            vec![("a", "b")]
+        );
+    }
+
+    #[test]
+    fn fewer_than_two() {
+        assert_eq!([1].into_iter().our_tuple_windows().next(), None);
+        assert_eq!(std::iter::empty::<i32>().our_tuple_windows().next(), None);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut iter = [1, 2, 3, 4].into_iter().our_tuple_windows();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(
+            std::iter::empty::<i32>().our_tuple_windows().size_hint(),
+            (0, Some(0))
+        );
+    }
+}
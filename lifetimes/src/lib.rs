@@ -1,9 +1,25 @@
+//! A `str::split`-style iterator used to explore lifetimes.
+//!
+//! Splitting only needs `core`, so the crate builds with `#![no_std]` when the default `std`
+//! feature is turned off (`cargo build -p lifetimes --no-default-features`). Helpers that
+//! allocate, like [`join`], are only available with `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 #[derive(Debug)]
 pub struct StrSplit<'haystack, D> {
     /// The remaining portion of the string to be split.
     remainder: Option<&'haystack str>,
     /// The delimiter used to split the string.
     delimiter: D,
+    /// How many more pieces may be produced; the last one holds the unsplit rest.
+    limit: Option<usize>,
+    /// Whether each piece keeps the delimiter that ends it.
+    inclusive: bool,
+    /// Whether empty pieces are skipped instead of returned.
+    skip_empty: bool,
+    /// Whether the remainder starts at an empty delimiter match that has already split
+    /// off a piece, so the next search must step over it.
+    after_empty_match: bool,
 }
 
 // Explanation:
@@ -22,14 +38,289 @@ impl<'haystack, D> StrSplit<'haystack, D> {
         StrSplit {
             remainder: Some(haystack),
             delimiter,
+            limit: None,
+            inclusive: false,
+            skip_empty: false,
+            after_empty_match: false,
+        }
+    }
+
+    /// Skips empty pieces, like `split_whitespace` does; shorthand for
+    /// [`StrSplitBuilder::skip_empty`].
+    pub fn skip_empty(mut self) -> Self {
+        self.skip_empty = true;
+        self
+    }
+
+    /// Returns how many bytes of `original` have been split off so far.
+    ///
+    /// The splitter only keeps what is left, not the start of the haystack, so `original`
+    /// must be the string it was created from. Once it is used from the back as well, the
+    /// count includes the bytes split off there, so it is only an offset into `original`
+    /// while splitting forwards.
+    pub fn consumed(&self, original: &'haystack str) -> usize {
+        original.len() - self.remainder.map_or(0, str::len)
+    }
+
+    /// Starts building a `StrSplit` with extra options, see [`StrSplitBuilder`].
+    pub fn builder(haystack: &'haystack str, delimiter: D) -> StrSplitBuilder<'haystack, D> {
+        StrSplitBuilder {
+            split: StrSplit::new(haystack, delimiter),
         }
     }
 }
 
+/// Configures how a [`StrSplit`] splits its haystack.
+///
+/// Created by [`StrSplit::builder`]; every option defaults to plain `str::split` behavior.
+#[derive(Debug)]
+pub struct StrSplitBuilder<'haystack, D> {
+    split: StrSplit<'haystack, D>,
+}
+
+impl<'haystack, D> StrSplitBuilder<'haystack, D> {
+    /// Produces at most `n` pieces, like `str::splitn`: the last one is the unsplit rest.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.split.limit = Some(n);
+        self
+    }
+
+    /// Keeps the delimiter at the end of each piece, like `str::split_inclusive`.
+    ///
+    /// An empty rest after the last delimiter is not returned as a piece.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.split.inclusive = inclusive;
+        self
+    }
+
+    /// Skips empty pieces, so consecutive, leading or trailing delimiters produce nothing.
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self {
+        self.split.skip_empty = skip_empty;
+        self
+    }
+
+    /// Returns the configured `StrSplit`.
+    pub fn build(self) -> StrSplit<'haystack, D> {
+        self.split
+    }
+}
+
 pub trait Delimiter {
     /// Finds the next occurrence of the delimiter in the string `s`.
     /// Returns the start and end indices of the delimiter.
     fn find_next(&self, s: &str) -> Option<(usize, usize)>;
+
+    /// Finds the last occurrence of the delimiter in the string `s`.
+    /// Returns the start and end indices of the delimiter.
+    ///
+    /// The default scans forward with `find_next` and keeps the last match, so it costs a
+    /// full pass over `s`; delimiters that can search from the back should override it.
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        let mut last = None;
+        let mut offset = 0;
+        while let Some(found) = self.find_next(&s[offset..]) {
+            let (start, end) = check_match(&s[offset..], found);
+            last = Some((offset + start, offset + end));
+            if end == 0 {
+                // An empty match at the very start would be found again forever;
+                // step over one character instead.
+                match s[offset..].chars().next() {
+                    Some(c) => offset += c.len_utf8(),
+                    None => break,
+                }
+            } else {
+                offset += end;
+            }
+        }
+        last
+    }
+
+    /// Finds all occurrences of the delimiter in `s`, returning their start and end indices.
+    ///
+    /// Each search resumes right after the previous match, so the matches never overlap.
+    /// After an empty match where the search started, it steps over one character, like
+    /// `str::match_indices("")`, and stops at the end of `s`. `StrSplit` splits forwards
+    /// at exactly these matches.
+    fn find_all(&self, s: &str) -> impl Iterator<Item = (usize, usize)>
+    where
+        Self: Sized,
+    {
+        // `None` once an empty match at the very end has been returned.
+        let mut offset = Some(0);
+        core::iter::from_fn(move || {
+            let from = offset?;
+            let (start, end) = check_match(&s[from..], self.find_next(&s[from..])?);
+            let found = (from + start, from + end);
+            offset = if end == 0 {
+                // An empty match right where the search started would be found again
+                // forever; step over one character instead, as `find_last` does.
+                s[from..].chars().next().map(|c| from + c.len_utf8())
+            } else {
+                // Empty matches further on (like `EveryNChars`) resume right at the match,
+                // as `StrSplit` does.
+                Some(found.1)
+            };
+            Some(found)
+        })
+    }
+}
+
+/// Runs `find_next`, checking that the match can be used to slice `s`.
+fn find_next_checked<D: Delimiter>(delimiter: &D, s: &str) -> Option<(usize, usize)> {
+    delimiter.find_next(s).map(|found| check_match(s, found))
+}
+
+/// Runs `find_last`, checking that the match can be used to slice `s`.
+fn find_last_checked<D: Delimiter>(delimiter: &D, s: &str) -> Option<(usize, usize)> {
+    delimiter.find_last(s).map(|found| check_match(s, found))
+}
+
+// A buggy `Delimiter` would otherwise surface as a byte-index panic deep inside the
+// splitter (or, for `start > end`, as silently overlapping pieces).
+fn check_match(s: &str, (start, end): (usize, usize)) -> (usize, usize) {
+    for i in [start, end] {
+        assert!(
+            s.is_char_boundary(i),
+            "delimiter returned non-char-boundary index {i}"
+        );
+    }
+    assert!(
+        start <= end,
+        "delimiter returned start {start} after end {end}"
+    );
+    (start, end)
+}
+
+impl<'haystack, D> StrSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    /// Splits off the next piece from the front, ignoring `limit` and `skip_empty`.
+    fn next_piece(&mut self) -> Option<&'haystack str> {
+        let remainder = self.remainder.as_mut()?;
+        // Searching again right at an empty match would find it forever; resume one
+        // character further on, or find nothing more at the end, as `find_all` does.
+        let search_from = if self.after_empty_match {
+            remainder.chars().next().map(char::len_utf8)
+        } else {
+            Some(0)
+        };
+        let found = search_from.and_then(|from| {
+            find_next_checked(&self.delimiter, &remainder[from..])
+                .map(|(start, end)| (from + start, from + end))
+        });
+        if let Some((delim_start, delim_end)) = found {
+            self.after_empty_match = search_from == Some(delim_end);
+            // If the delimiter is found; inclusive pieces keep it
+            let piece_end = if self.inclusive {
+                delim_end
+            } else {
+                delim_start
+            };
+            let piece = &remainder[..piece_end];
+            *remainder = &remainder[delim_end..];
+            Some(piece)
+        } else if self.inclusive && remainder.is_empty() {
+            // The last delimiter ended the haystack; there is no trailing piece
+            self.remainder = None;
+            None
+        } else {
+            // No more delimiters found; return the remainder
+            self.remainder.take()
+        }
+    }
+
+    /// Splits off the last piece from the back, ignoring `limit` and `skip_empty`.
+    fn next_back_piece(&mut self) -> Option<&'haystack str> {
+        let remainder = self.remainder.as_mut()?;
+        let mut search_end = remainder.len();
+        if self.inclusive {
+            if remainder.is_empty() {
+                // The last delimiter ended the haystack; there is no trailing piece
+                self.remainder = None;
+                return None;
+            }
+            // A delimiter at the very end belongs to the last piece, so split at the one before it
+            if let Some((delim_start, delim_end)) = find_last_checked(&self.delimiter, remainder) {
+                if delim_end == remainder.len() {
+                    search_end = delim_start;
+                }
+            }
+        }
+        if let Some((delim_start, delim_end)) =
+            find_last_checked(&self.delimiter, &remainder[..search_end])
+        {
+            let piece = &remainder[delim_end..];
+            let rest_end = if self.inclusive {
+                delim_end
+            } else {
+                delim_start
+            };
+            *remainder = &remainder[..rest_end];
+            Some(piece)
+        } else {
+            // No delimiters left; whatever remains is the first (and last) piece
+            self.remainder.take()
+        }
+    }
+
+    /// Returns everything that is left as one piece, once `limit` allows only one more.
+    fn take_rest(&mut self, from_back: bool) -> Option<&'haystack str> {
+        if self.skip_empty && !self.inclusive {
+            // Drop delimiters on the side we are coming from; they would only split off empty pieces
+            if let Some(ref mut remainder) = self.remainder {
+                if from_back {
+                    while let Some((delim_start, delim_end)) =
+                        find_last_checked(&self.delimiter, remainder)
+                    {
+                        if delim_end != remainder.len() || delim_start == delim_end {
+                            break;
+                        }
+                        *remainder = &remainder[..delim_start];
+                    }
+                } else {
+                    while let Some((0, delim_end)) = find_next_checked(&self.delimiter, remainder) {
+                        if delim_end == 0 {
+                            break;
+                        }
+                        *remainder = &remainder[delim_end..];
+                    }
+                }
+            }
+        }
+        let rest = self.remainder.take()?;
+        if rest.is_empty() && (self.skip_empty || self.inclusive) {
+            None
+        } else {
+            Some(rest)
+        }
+    }
+
+    /// Pulls pieces with `next_piece` (or `next_back_piece`), applying `limit` and `skip_empty`.
+    fn next_limited(&mut self, from_back: bool) -> Option<&'haystack str> {
+        match self.limit {
+            Some(0) => return None,
+            Some(1) => {
+                self.limit = Some(0);
+                return self.take_rest(from_back);
+            }
+            _ => {}
+        }
+        loop {
+            let piece = if from_back {
+                self.next_back_piece()?
+            } else {
+                self.next_piece()?
+            };
+            if self.skip_empty && piece.is_empty() {
+                continue;
+            }
+            if let Some(ref mut limit) = self.limit {
+                *limit -= 1;
+            }
+            return Some(piece);
+        }
+    }
 }
 
 impl<'haystack, D> Iterator for StrSplit<'haystack, D>
@@ -40,72 +331,1606 @@ where
 
     /// Advances the iterator and returns the next split string slice.
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(ref mut remainder) = self.remainder {
-            // If there is a remainder to process
-            if let Some((delim_start, delim_end)) = self.delimiter.find_next(remainder) {
-                // If the delimiter is found
-                let until_delim = &remainder[..delim_start];
-                *remainder = &remainder[delim_end..];
-                Some(until_delim)
-            } else {
-                // No more delimiters found; return the remainder
-                self.remainder.take()
-            }
+        self.next_limited(false)
+    }
+
+    /// Reports at least one more piece while there is a remainder, which is always the
+    /// case for plain splitting, so `collect` can allocate before the first push.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some(remainder) = self.remainder else {
+            return (0, Some(0));
+        };
+        // Skipped empty pieces, or an inclusive split whose last delimiter ended the
+        // haystack, may leave nothing more to yield.
+        let may_be_done = self.skip_empty || (self.inclusive && remainder.is_empty());
+        let lower = if may_be_done || self.limit == Some(0) {
+            0
         } else {
-            // No remainder left; iteration is complete
-            None
+            1
+        };
+        // A zero-width delimiter can match anywhere, so only `limit` bounds the count.
+        (lower, self.limit)
+    }
+
+    /// Counts the remaining pieces without slicing them out: one more than the number of delimiters.
+    fn count(self) -> usize {
+        if self.limit.is_some() || self.inclusive || self.skip_empty {
+            // The shortcut only holds for plain splitting; count piece by piece otherwise.
+            return self.fold(0, |count, _| count + 1);
+        }
+        match self.remainder {
+            // An empty match that already split off a piece is still at the start of the
+            // remainder, and `find_all` would find it again.
+            Some(remainder) => {
+                let already_split = usize::from(self.after_empty_match);
+                self.delimiter.find_all(remainder).count() + 1 - already_split
+            }
+            None => 0,
         }
     }
 }
 
+/// Only for delimiters whose matches can't overlap, see [`DoubleEndedDelimiter`].
+impl<'haystack, D> DoubleEndedIterator for StrSplit<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    /// Returns the last split string slice, shrinking the remainder from the back.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_limited(true)
+    }
+}
+
+/// Once `next` or `next_back` has returned `None`, the remainder is gone (or `limit` is
+/// used up), so every later call returns `None` too.
+impl<'haystack, D> core::iter::FusedIterator for StrSplit<'haystack, D> where D: Delimiter {}
+
+impl<'haystack, D> StrSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    /// Splits at the first delimiter, returning the pieces before and after it, like
+    /// `str::split_once`; `None` if the delimiter doesn't occur.
+    ///
+    /// Only the unsplit remainder is looked at: `limit`, `inclusive` and `skip_empty`
+    /// don't apply, and the delimiter itself is in neither half.
+    pub fn split_once(mut self) -> Option<(&'haystack str, &'haystack str)> {
+        let remainder = self.remainder.take()?;
+        let (delim_start, delim_end) = find_next_checked(&self.delimiter, remainder)?;
+        Some((&remainder[..delim_start], &remainder[delim_end..]))
+    }
+
+    /// Splits at the last delimiter, like `str::rsplit_once`; the counterpart of
+    /// [`split_once`](StrSplit::split_once), with the same options ignored.
+    pub fn rsplit_once(mut self) -> Option<(&'haystack str, &'haystack str)> {
+        let remainder = self.remainder.take()?;
+        let (delim_start, delim_end) = find_last_checked(&self.delimiter, remainder)?;
+        Some((&remainder[..delim_start], &remainder[delim_end..]))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'haystack, D> StrSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    /// Appends the remaining pieces to `buf`, and returns it.
+    ///
+    /// Unlike `collect`, this never allocates a new `Vec`, so a loop that splits many
+    /// strings can clear and reuse one buffer, which keeps its capacity.
+    pub fn collect_into<'v>(self, buf: &'v mut Vec<&'haystack str>) -> &'v mut Vec<&'haystack str> {
+        buf.extend(self);
+        buf
+    }
+}
+
 // Lifetimes in `Iterator` implementation:
 // - The `Item` type is `&'haystack str`, ensuring that each slice returned does not outlive the `haystack`.
 // - This is enforced by the lifetime `'haystack` in the struct definition and `impl`.
 
-impl Delimiter for &str {
-    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
-        // Finds the next occurrence of the substring delimiter
-        s.find(*self).map(|start| (start, start + self.len()))
+impl<'haystack, D> StrSplit<'haystack, D> {
+    /// Turns this splitter into a [`PeekableStrSplit`] with one piece of lookahead.
+    pub fn peekable_split(self) -> PeekableStrSplit<'haystack, D> {
+        PeekableStrSplit {
+            inner: self,
+            peeked: None,
+        }
     }
 }
 
-impl Delimiter for char {
-    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
-        // Finds the next occurrence of the character delimiter
-        s.char_indices()
-            .find(|&(_, c)| c == *self)
-            .map(|(start, _)| (start, start + self.len_utf8()))
+impl<'haystack, D> StrSplit<'haystack, D> {
+    /// Pairs each piece with its byte offset in the haystack, e.g. for error reporting.
+    ///
+    /// Offsets count from where the remainder starts when `indexed` is called, which for
+    /// a fresh `StrSplit` is the start of the original haystack.
+    pub fn indexed(self) -> Indexed<'haystack, D> {
+        Indexed {
+            origin: self.remainder.unwrap_or(""),
+            inner: self,
+        }
+    }
+
+    /// Yields the byte range of each piece instead of the piece, for callers that slice
+    /// the haystack themselves (e.g. to build spans).
+    ///
+    /// The ranges are measured from the same place as [`indexed`](StrSplit::indexed)'s offsets.
+    pub fn ranges(self) -> Ranges<'haystack, D> {
+        Ranges {
+            inner: self.indexed(),
+        }
     }
 }
 
-// Explanation:
-// Implementing `Delimiter` for `&str` and `char` allows `StrSplit` to accept both types as delimiters.
-// This showcases Rust's trait system and how it can be used to create flexible and reusable code.
+/// A `StrSplit` that also yields the byte offset of each piece.
+///
+/// This struct is created by the [`StrSplit::indexed`] method.
+#[derive(Debug)]
+pub struct Indexed<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+    /// The string that offsets are measured from; every piece is a subslice of it.
+    origin: &'haystack str,
+}
 
-pub fn until_char(s: &str, c: char) -> &str {
-    // Returns the substring until the first occurrence of the character `c`.
-    StrSplit::new(s, c)
-        .next()
-        .expect("StrSplit always gives at least one result")
+impl<'haystack, D> Indexed<'haystack, D> {
+    fn offset_of(&self, piece: &'haystack str) -> usize {
+        // Both slices point into the same haystack, so the address difference is the offset.
+        piece.as_ptr() as usize - self.origin.as_ptr() as usize
+    }
 }
 
-#[test]
-fn until_char_test() {
-    assert_eq!(until_char("hello world", 'o'), "hell");
+impl<'haystack, D> Iterator for Indexed<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = (usize, &'haystack str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let piece = self.inner.next()?;
+        Some((self.offset_of(piece), piece))
+    }
 }
 
-#[test]
-fn it_works() {
-    let haystack = "a b c d e";
-    let letters: Vec<_> = StrSplit::new(haystack, " ").collect();
-    assert_eq!(letters, vec!["a", "b", "c", "d", "e"]);
+impl<'haystack, D> DoubleEndedIterator for Indexed<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let piece = self.inner.next_back()?;
+        Some((self.offset_of(piece), piece))
+    }
 }
 
-#[test]
-fn tail() {
-    let haystack = "a b c d ";
-    let letters: Vec<_> = StrSplit::new(haystack, " ").collect();
-    assert_eq!(letters, vec!["a", "b", "c", "d", ""]);
+/// A `StrSplit` that yields the byte range of each piece.
+///
+/// This struct is created by the [`StrSplit::ranges`] method.
+#[derive(Debug)]
+pub struct Ranges<'haystack, D> {
+    inner: Indexed<'haystack, D>,
+}
+
+impl<'haystack, D> Iterator for Ranges<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = core::ops::Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, piece) = self.inner.next()?;
+        Some(start..start + piece.len())
+    }
+}
+
+impl<'haystack, D> DoubleEndedIterator for Ranges<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, piece) = self.inner.next_back()?;
+        Some(start..start + piece.len())
+    }
+}
+
+impl<'haystack, D> StrSplit<'haystack, D> {
+    /// Trims leading and trailing whitespace from each piece, as with `str::trim`.
+    ///
+    /// Pieces that are only whitespace become `""` but are still yielded; chain
+    /// [`Trimmed::skip_empty`] to drop them too.
+    pub fn trimmed(self) -> Trimmed<'haystack, D> {
+        Trimmed {
+            inner: self,
+            skip_empty: false,
+        }
+    }
+}
+
+/// A `StrSplit` that trims whitespace from each piece.
+///
+/// This struct is created by the [`StrSplit::trimmed`] method.
+#[derive(Debug)]
+pub struct Trimmed<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+    skip_empty: bool,
+}
+
+impl<'haystack, D> Trimmed<'haystack, D> {
+    /// Also drops pieces that are empty after trimming.
+    ///
+    /// This differs from [`StrSplit::skip_empty`], which only drops pieces that were
+    /// empty before trimming, so it would keep `"   "` (as `""`).
+    pub fn skip_empty(mut self) -> Self {
+        self.skip_empty = true;
+        self
+    }
+}
+
+impl<'haystack, D> Iterator for Trimmed<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let piece = self.inner.next()?.trim();
+            if !(self.skip_empty && piece.is_empty()) {
+                return Some(piece);
+            }
+        }
+    }
+}
+
+impl<'haystack, D> DoubleEndedIterator for Trimmed<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let piece = self.inner.next_back()?.trim();
+            if !(self.skip_empty && piece.is_empty()) {
+                return Some(piece);
+            }
+        }
+    }
+}
+
+/// A `StrSplit` with one piece of lookahead that keeps the `'haystack` lifetime.
+///
+/// Unlike `std::iter::Peekable`, it can also hand back the unconsumed rest of the haystack.
+#[derive(Debug)]
+pub struct PeekableStrSplit<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+    /// The peeked piece, together with the remainder as it was before peeking.
+    peeked: Option<(Option<&'haystack str>, Option<&'haystack str>)>,
+}
+
+impl<'haystack, D> PeekableStrSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    /// Returns the next piece without advancing the iterator.
+    pub fn peek(&mut self) -> Option<&&'haystack str> {
+        let inner = &mut self.inner;
+        self.peeked
+            .get_or_insert_with(|| {
+                let remainder = inner.remainder;
+                (inner.next(), remainder)
+            })
+            .0
+            .as_ref()
+    }
+
+    /// Returns the part of the haystack that has not been yielded by `next` yet.
+    ///
+    /// A piece that has only been peeked at is still part of the remainder.
+    pub fn remainder(&self) -> Option<&'haystack str> {
+        match self.peeked {
+            Some((_, remainder)) => remainder,
+            None => self.inner.remainder,
+        }
+    }
+}
+
+impl<'haystack, D> Iterator for PeekableStrSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some((piece, _)) => piece,
+            None => self.inner.next(),
+        }
+    }
+}
+
+/// Splits `haystack` into at most `n` pieces, like `str::splitn`.
+///
+/// After `n - 1` splits, the last piece is the rest of the string, delimiters and all.
+/// `n == 0` yields nothing, and `n == 1` the whole string unsplit. This is
+/// [`StrSplitBuilder::limit`] as a function.
+pub fn splitn<D: Delimiter>(haystack: &str, n: usize, delimiter: D) -> SplitN<'_, D> {
+    SplitN {
+        inner: StrSplit::builder(haystack, delimiter).limit(n).build(),
+    }
+}
+
+/// An iterator over at most `n` pieces of a string.
+///
+/// This struct is created by the [`splitn`] function.
+#[derive(Debug)]
+pub struct SplitN<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+}
+
+impl<'haystack, D> Iterator for SplitN<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'haystack, D> core::iter::FusedIterator for SplitN<'haystack, D> where D: Delimiter {}
+
+/// Splits `haystack` after each delimiter, keeping it at the end of its piece, like
+/// `str::split_inclusive`.
+///
+/// A trailing piece without a delimiter is still returned, but a delimiter at the very
+/// end doesn't produce an empty one after it. This is [`StrSplitBuilder::inclusive`] as
+/// a function.
+pub fn split_inclusive<D: Delimiter>(haystack: &str, delimiter: D) -> SplitInclusive<'_, D> {
+    SplitInclusive {
+        inner: StrSplit::builder(haystack, delimiter)
+            .inclusive(true)
+            .build(),
+    }
+}
+
+/// An iterator over the pieces of a string, each ending with its delimiter.
+///
+/// This struct is created by the [`split_inclusive`] function.
+#[derive(Debug)]
+pub struct SplitInclusive<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+}
+
+impl<'haystack, D> Iterator for SplitInclusive<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'haystack, D> DoubleEndedIterator for SplitInclusive<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'haystack, D> core::iter::FusedIterator for SplitInclusive<'haystack, D> where D: Delimiter {}
+
+/// Splits `haystack` from the right into at most `n` pieces.
+///
+/// The last piece holds everything to the left of the split points, like `str::rsplitn`.
+pub fn rsplitn<D: Delimiter>(haystack: &str, n: usize, delimiter: D) -> RSplitN<'_, D> {
+    RSplitN {
+        inner: StrSplit::new(haystack, delimiter),
+        remaining: n,
+    }
+}
+
+/// An iterator over at most `n` pieces of a string, split from the right.
+///
+/// This struct is created by the [`rsplitn`] function.
+#[derive(Debug)]
+pub struct RSplitN<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+    /// How many more pieces may be produced.
+    remaining: usize,
+}
+
+impl<'haystack, D> Iterator for RSplitN<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            1 => {
+                // The last allowed piece is the unsplit left remainder.
+                self.remaining = 0;
+                self.inner.remainder.take()
+            }
+            _ => {
+                self.remaining -= 1;
+                // Searches from the back only, so any delimiter works, as in `StrRSplit`.
+                self.inner.next_limited(true)
+            }
+        }
+    }
+
+    /// At least one more piece while there is a remainder, and never more than `n`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.inner.remainder.is_none() {
+            return (0, Some(0));
+        }
+        (self.remaining.min(1), Some(self.remaining))
+    }
+}
+
+/// Once the remainder is gone (or `n` pieces have been produced), every call returns `None`.
+impl<'haystack, D> core::iter::FusedIterator for RSplitN<'haystack, D> where D: Delimiter {}
+
+/// Splits `haystack` from the right, yielding the pieces last to first, like `str::rsplit`.
+///
+/// Each piece is found with [`Delimiter::find_last`] as it is asked for, so nothing is
+/// collected up front.
+pub fn rsplit<D: Delimiter>(haystack: &str, delimiter: D) -> StrRSplit<'_, D> {
+    StrRSplit {
+        inner: StrSplit::new(haystack, delimiter),
+    }
+}
+
+/// A [`StrSplit`] running backwards.
+///
+/// This struct is created by the [`rsplit`] function.
+#[derive(Debug)]
+pub struct StrRSplit<'haystack, D> {
+    inner: StrSplit<'haystack, D>,
+}
+
+impl<'haystack, D> Iterator for StrRSplit<'haystack, D>
+where
+    D: Delimiter,
+{
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Only ever searching from the back is consistent for any delimiter, like
+        // `str::rsplit`; it is mixing in `next_back` that needs the matches not to overlap.
+        self.inner.next_limited(true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'haystack, D> DoubleEndedIterator for StrRSplit<'haystack, D>
+where
+    D: DoubleEndedDelimiter,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'haystack, D> core::iter::FusedIterator for StrRSplit<'haystack, D> where D: Delimiter {}
+
+/// A [`Delimiter`] whose matches can't overlap each other, so searching from the front
+/// and from the back finds the same ones, and a [`StrSplit`] can be consumed from both
+/// ends.
+///
+/// Delimiters that match a single char qualify. A substring can overlap itself: in
+/// `"aaa"`, `"aa"` matches `0..2` searching forwards but `1..3` searching backwards, so
+/// splitting on `&str` (like std's `Split<&str>`) only goes one way; [`rsplit`] and
+/// [`rsplitn`] still split from the back alone.
+///
+/// ```compile_fail,E0277
+/// use lifetimes::StrSplit;
+///
+/// // Would give ["", "a"] instead of ["a", ""].
+/// let pieces: Vec<_> = StrSplit::new("aaa", "aa").rev().collect();
+/// ```
+pub trait DoubleEndedDelimiter: Delimiter {}
+
+impl DoubleEndedDelimiter for char {}
+impl<F> DoubleEndedDelimiter for F where F: Fn(char) -> bool {}
+impl DoubleEndedDelimiter for &[char] {}
+impl<const N: usize> DoubleEndedDelimiter for [char; N] {}
+impl DoubleEndedDelimiter for core::ops::RangeInclusive<char> {}
+impl<F> DoubleEndedDelimiter for CharPredicate<F> where F: Fn(char) -> bool {}
+// Its boundaries are counted from the start of whatever is searched, and splitting off
+// whole chunks from either end keeps that start on a boundary.
+impl DoubleEndedDelimiter for EveryNChars {}
+
+impl Delimiter for &str {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        // Finds the next occurrence of the substring delimiter
+        s.find(*self).map(|start| (start, start + self.len()))
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        // Searches from the back, so the last match is found without a forward pass
+        s.rfind(*self).map(|start| (start, start + self.len()))
+    }
+}
+
+impl Delimiter for char {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        // Finds the next occurrence of the character delimiter
+        s.char_indices()
+            .find(|&(_, c)| c == *self)
+            .map(|(start, _)| (start, start + self.len_utf8()))
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        // Finds the last occurrence of the character delimiter
+        s.rfind(*self).map(|start| (start, start + self.len_utf8()))
+    }
+}
+
+impl<F> Delimiter for F
+where
+    F: Fn(char) -> bool,
+{
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        // Finds the next character matching the predicate
+        s.char_indices()
+            .find(|&(_, c)| self(c))
+            .map(|(start, c)| (start, start + c.len_utf8()))
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        // Finds the last character matching the predicate
+        s.char_indices()
+            .rev()
+            .find(|&(_, c)| self(c))
+            .map(|(start, c)| (start, start + c.len_utf8()))
+    }
+}
+
+// A set of chars matches any one of them, like `str::split(&['a', 'b'][..])`. Both forms
+// reuse the predicate impl above rather than duplicating its search.
+impl Delimiter for &[char] {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).find_last(s)
+    }
+}
+
+impl<const N: usize> Delimiter for [char; N] {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_slice().find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_slice().find_last(s)
+    }
+}
+
+// A char range matches any char inside it, e.g. `'0'..='9'` for any ASCII digit. The
+// predicate impl returns the matched char's own UTF-8 length, so multi-byte ranges work.
+impl Delimiter for core::ops::RangeInclusive<char> {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).find_last(s)
+    }
+}
+
+// Owned and borrowed `String`s search as the `&str` they deref to.
+#[cfg(feature = "std")]
+impl Delimiter for String {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_str().find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_str().find_last(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Delimiter for &String {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_str().find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        self.as_str().find_last(s)
+    }
+}
+
+// Explanation:
+// Implementing `Delimiter` for `&str`, `String`, `char` and sets of chars allows `StrSplit` to accept
+// the same delimiters as `str::split`. The blanket impl for `Fn(char) -> bool` also accepts closures
+// and functions like `char::is_whitespace`.
+// This showcases Rust's trait system and how it can be used to create flexible and reusable code.
+
+/// A substring delimiter that matches regardless of ASCII case.
+///
+/// Only ASCII letters are folded (`'X'` matches `'x'`); any non-ASCII character has to
+/// match exactly, so `"Ä"` will not match `"ä"`.
+#[derive(Debug, Clone, Copy)]
+pub struct AsciiCaseInsensitive<'a>(pub &'a str);
+
+impl Delimiter for AsciiCaseInsensitive<'_> {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        // Compare byte windows. ASCII folding never turns an ASCII byte into a UTF-8
+        // lead or continuation byte, so a window matching the (valid UTF-8) needle always
+        // starts and ends on char boundaries of `s`.
+        let needle = self.0.as_bytes();
+        if needle.is_empty() {
+            // Like `str::find("")`, an empty delimiter matches at the start.
+            return Some((0, 0));
+        }
+        s.as_bytes()
+            .windows(needle.len())
+            .position(|window| window.eq_ignore_ascii_case(needle))
+            .map(|start| (start, start + needle.len()))
+    }
+}
+
+/// A delimiter matching any char that satisfies a predicate, like `char::is_numeric`.
+///
+/// A bare `Fn(char) -> bool` is already a [`Delimiter`] through the blanket impl, and this
+/// delegates to it. The wrapper gives the delimiter a named type, for example to store it
+/// in a struct field or to implement other traits on it.
+#[derive(Debug, Clone, Copy)]
+pub struct CharPredicate<F>(pub F);
+
+impl<F> Delimiter for CharPredicate<F>
+where
+    F: Fn(char) -> bool,
+{
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        self.0.find_next(s)
+    }
+
+    fn find_last(&self, s: &str) -> Option<(usize, usize)> {
+        self.0.find_last(s)
+    }
+}
+
+/// A zero-width delimiter after every `N` chars, so `StrSplit` yields `N`-char chunks.
+///
+/// Counts chars, not bytes, so chunks never cut a multi-byte char. The last chunk holds
+/// whatever is left and may be shorter than `N`; there is never an empty trailing chunk.
+///
+/// # Panics
+///
+/// Searching with `EveryNChars(0)` panics, since zero-char chunks would never advance.
+#[derive(Debug, Clone, Copy)]
+pub struct EveryNChars(pub usize);
+
+impl Delimiter for EveryNChars {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        assert!(self.0 > 0, "EveryNChars needs a chunk size of at least 1");
+        // The boundary after the first `N` chars, as long as at least one char follows it;
+        // with `N` or fewer chars left, the rest is the last chunk.
+        let (boundary, _) = s.char_indices().nth(self.0)?;
+        Some((boundary, boundary))
+    }
+}
+
+/// Splits `haystack` on runs of whitespace, like `str::split_whitespace`.
+///
+/// Built from a `char::is_whitespace` delimiter with empty pieces skipped, so runs of
+/// whitespace collapse and leading or trailing whitespace produces no empty pieces.
+pub fn split_whitespace(haystack: &str) -> StrSplit<'_, fn(char) -> bool> {
+    StrSplit::new(haystack, char::is_whitespace as fn(char) -> bool).skip_empty()
+}
+
+/// Concatenates `pieces` with `sep` between them; the inverse of splitting on `sep`.
+///
+/// Needs the `std` feature, since it allocates a `String`.
+#[cfg(feature = "std")]
+pub fn join<'a, I>(pieces: I, sep: &str) -> String
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut pieces = pieces.into_iter();
+    let mut joined = String::new();
+    if let Some(first) = pieces.next() {
+        joined.push_str(first);
+        for piece in pieces {
+            joined.push_str(sep);
+            joined.push_str(piece);
+        }
+    }
+    joined
+}
+
+pub fn until_char(s: &str, c: char) -> &str {
+    // Returns the substring until the first occurrence of the character `c`.
+    until_delimiter(s, c)
+}
+
+/// Returns the part of `s` before the first occurrence of `delimiter`, or all of `s` if
+/// there is none.
+///
+/// A plain `StrSplit` always yields at least one piece, so this can't actually fail; see
+/// [`try_until_delimiter`] for a version that treats empty input as "nothing found".
+pub fn until_delimiter<D: Delimiter>(s: &str, delimiter: D) -> &str {
+    StrSplit::new(s, delimiter)
+        .next()
+        .expect("StrSplit always gives at least one result")
+}
+
+/// Like [`until_delimiter`], but returns `None` instead of `Some("")` when `s` is empty.
+///
+/// `until_delimiter` can't tell "the input was empty" apart from "the input starts with
+/// the delimiter", since both return `""`; this lets callers handle the first case.
+pub fn try_until_delimiter<D: Delimiter>(s: &str, delimiter: D) -> Option<&str> {
+    if s.is_empty() {
+        return None;
+    }
+    StrSplit::new(s, delimiter).next()
+}
+
+#[test]
+fn split_once_test() {
+    assert_eq!(
+        StrSplit::new("key=val=ue", '=').split_once(),
+        Some(("key", "val=ue"))
+    );
+    assert_eq!(StrSplit::new("no delimiter", '=').split_once(), None);
+    assert_eq!(
+        StrSplit::new("a, b, c", ", ").split_once(),
+        Some(("a", "b, c"))
+    );
+    assert_eq!(StrSplit::new("=", '=').split_once(), Some(("", "")));
+
+    // Only what hasn't been split off yet is looked at.
+    let mut split = StrSplit::new("a=b=c", '=');
+    split.next();
+    assert_eq!(split.split_once(), Some(("b", "c")));
+}
+
+#[test]
+fn rsplit_once_test() {
+    assert_eq!(
+        StrSplit::new("a.b.c", '.').rsplit_once(),
+        Some(("a.b", "c"))
+    );
+    assert_eq!(StrSplit::new("abc", '.').rsplit_once(), None);
+    assert_eq!(
+        StrSplit::new("a::b::", "::").rsplit_once(),
+        Some(("a::b", ""))
+    );
+    assert_eq!(
+        StrSplit::new("x=1", '=').rsplit_once(),
+        StrSplit::new("x=1", '=').split_once()
+    );
+}
+
+#[test]
+fn size_hint_test() {
+    let mut split = StrSplit::new("a b", ' ');
+    assert_eq!(split.size_hint(), (1, None));
+    split.next();
+    split.next();
+    assert_eq!(split.size_hint(), (0, Some(0)));
+    assert_eq!(StrSplit::new("", ' ').size_hint(), (1, None));
+
+    assert_eq!(
+        StrSplit::builder("a b c", ' ').limit(2).build().size_hint(),
+        (1, Some(2))
+    );
+    assert_eq!(
+        StrSplit::builder("a", ' ').limit(0).build().size_hint(),
+        (0, Some(0))
+    );
+    assert_eq!(StrSplit::new("  ", ' ').skip_empty().size_hint(), (0, None));
+
+    let mut inclusive = StrSplit::builder("a\n", '\n').inclusive(true).build();
+    assert_eq!(inclusive.size_hint(), (1, None));
+    inclusive.next();
+    assert_eq!(inclusive.size_hint(), (0, None));
+    assert_eq!(inclusive.next(), None);
+}
+
+#[test]
+fn size_hint_bounds_hold() {
+    type Build = fn(&str) -> StrSplit<'_, char>;
+    let builds: [Build; 4] = [
+        |h| StrSplit::new(h, ','),
+        |h| StrSplit::new(h, ',').skip_empty(),
+        |h| StrSplit::builder(h, ',').limit(2).build(),
+        |h| StrSplit::builder(h, ',').inclusive(true).build(),
+    ];
+    for haystack in SPLIT_SAMPLES {
+        for build in builds {
+            let total = build(haystack).count();
+            for taken in 0..=total {
+                let mut split = build(haystack);
+                split.by_ref().take(taken).for_each(drop);
+                let (lower, upper) = split.size_hint();
+                let left = total - taken;
+                assert!(lower <= left, "{haystack:?}: {lower} > {left}");
+                assert!(upper.is_none_or(|upper| left <= upper), "{haystack:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn stays_exhausted() {
+    fn assert_fused<I: core::iter::FusedIterator>(mut iter: I) {
+        while iter.next().is_some() {}
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+    }
+    for haystack in SPLIT_SAMPLES {
+        assert_fused(StrSplit::new(haystack, ','));
+        assert_fused(StrSplit::new(haystack, ',').skip_empty());
+        assert_fused(StrSplit::builder(haystack, ',').limit(2).build());
+        assert_fused(StrSplit::builder(haystack, ',').inclusive(true).build());
+        assert_fused(StrSplit::new(haystack, ',').rev());
+    }
+}
+
+#[test]
+fn ranges_test() {
+    let haystack = "ab:cd";
+    let ranges: Vec<_> = StrSplit::new(haystack, ':').ranges().collect();
+    assert_eq!(ranges, [0..2, 3..5]);
+    assert_eq!(&haystack[ranges[1].clone()], "cd");
+
+    let haystack = "a, b,";
+    let pieces: Vec<_> = StrSplit::new(haystack, ", ").collect();
+    for (range, piece) in StrSplit::new(haystack, ", ").ranges().zip(pieces) {
+        assert_eq!(&haystack[range], piece);
+    }
+    let mut empty = StrSplit::new("", ',').ranges();
+    assert_eq!(empty.next(), Some(0..0));
+    assert_eq!(empty.next(), None);
+    assert_eq!(
+        StrSplit::new("x,yz", ',')
+            .ranges()
+            .rev()
+            .collect::<Vec<_>>(),
+        [2..4, 0..1]
+    );
+}
+
+#[test]
+fn consumed_test() {
+    let original = "key=value;next";
+    let mut split = StrSplit::new(original, ';');
+    assert_eq!(split.consumed(original), 0);
+    assert_eq!(split.next(), Some("key=value"));
+    // The piece and its delimiter.
+    assert_eq!(split.consumed(original), 10);
+    assert_eq!(&original[split.consumed(original)..], "next");
+    split.next();
+    assert_eq!(split.consumed(original), original.len());
+}
+
+#[test]
+fn until_char_test() {
+    assert_eq!(until_char("hello world", 'o'), "hell");
+}
+
+#[test]
+fn until_delimiter_test() {
+    assert_eq!(until_delimiter("key=value", "="), "key");
+    assert_eq!(until_delimiter("no delimiter", "="), "no delimiter");
+    assert_eq!(until_delimiter("=value", "="), "");
+    assert_eq!(until_delimiter("", "="), "");
+}
+
+#[test]
+fn try_until_delimiter_test() {
+    assert_eq!(try_until_delimiter("", '='), None);
+    assert_eq!(try_until_delimiter("key=value", '='), Some("key"));
+    assert_eq!(try_until_delimiter("=value", '='), Some(""));
+    assert_eq!(try_until_delimiter("plain", '='), Some("plain"));
+}
+
+#[test]
+fn it_works() {
+    let haystack = "a b c d e";
+    let letters: Vec<_> = StrSplit::new(haystack, " ").collect();
+    assert_eq!(letters, vec!["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn tail() {
+    let haystack = "a b c d ";
+    let letters: Vec<_> = StrSplit::new(haystack, " ").collect();
+    assert_eq!(letters, vec!["a", "b", "c", "d", ""]);
+}
+
+#[test]
+fn peek_does_not_advance() {
+    let mut split = StrSplit::new("a b c", " ").peekable_split();
+    assert_eq!(split.peek(), Some(&"a"));
+    assert_eq!(split.peek(), Some(&"a"));
+    assert_eq!(split.remainder(), Some("a b c"));
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.remainder(), Some("b c"));
+    assert_eq!(split.peek(), Some(&"b"));
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.next(), Some("c"));
+    assert_eq!(split.peek(), None);
+    assert_eq!(split.remainder(), None);
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn from_the_back() {
+    let mut split = StrSplit::new("a b c", ' ');
+    assert_eq!(split.next_back(), Some("c"));
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next_back(), Some("b"));
+    assert_eq!(split.next(), None);
+    assert_eq!(split.next_back(), None);
+
+    let letters: Vec<_> = StrSplit::new("a b c d ", ' ').rev().collect();
+    assert_eq!(letters, vec!["", "d", "c", "b", "a"]);
+}
+
+#[test]
+fn find_last_default() {
+    assert_eq!(AsciiCaseInsensitive("x").find_last("aXbxc"), Some((3, 4)));
+    assert_eq!(AsciiCaseInsensitive("x").find_last("abc"), None);
+    assert_eq!(AsciiCaseInsensitive("").find_last("ab"), Some((2, 2)));
+}
+
+#[test]
+fn find_last_char_and_str() {
+    assert_eq!(','.find_last("a,b,c"), Some((3, 4)));
+    assert_eq!(','.find_last("a,,"), Some((2, 3)));
+    assert_eq!('é'.find_last("éaé"), Some((3, 5)));
+    assert_eq!(','.find_last("abc"), None);
+
+    assert_eq!(", ".find_last("a, b, c"), Some((4, 6)));
+    assert_eq!("ab".find_last("abab"), Some((2, 4)));
+    assert_eq!("ab".find_last("abc"), Some((0, 2)));
+    assert_eq!("ab".find_last("ba"), None);
+    assert_eq!("".find_last("ab"), Some((2, 2)));
+
+    // Each override agrees with the forward-scanning default.
+    for haystack in ["", "a", "a,b", ",,", "a,,b,", "éa,é,"] {
+        assert_eq!(','.find_last(haystack), (|c| c == ',').find_last(haystack));
+        assert_eq!(
+            ",".find_last(haystack),
+            AsciiCaseInsensitive(",").find_last(haystack),
+            "{haystack:?}"
+        );
+    }
+}
+
+/// Claims every match is the second byte of `s`, which is wrong for multi-byte chars.
+#[cfg(test)]
+struct SecondByte;
+
+#[cfg(test)]
+impl Delimiter for SecondByte {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        (s.len() > 1).then_some((1, 2))
+    }
+}
+
+#[test]
+#[should_panic(expected = "delimiter returned non-char-boundary index 1")]
+fn non_char_boundary_delimiter() {
+    // Fine on ASCII, but 1 is inside the two-byte 'é'.
+    assert_eq!(StrSplit::new("abc", SecondByte).next(), Some("a"));
+    StrSplit::new("éa", SecondByte).next();
+}
+
+#[test]
+#[should_panic(expected = "delimiter returned non-char-boundary index 2")]
+fn non_char_boundary_delimiter_from_the_back() {
+    rsplit("aéé", SecondByte).next();
+}
+
+#[test]
+fn find_all_ranges() {
+    assert_eq!(
+        " ".find_all("a b  c").collect::<Vec<_>>(),
+        vec![(1, 2), (3, 4), (4, 5)]
+    );
+    assert_eq!(
+        "ab".find_all("abab").collect::<Vec<_>>(),
+        vec![(0, 2), (2, 4)]
+    );
+    assert_eq!(
+        "aa".find_all("aaaaa").collect::<Vec<_>>(),
+        vec![(0, 2), (2, 4)]
+    );
+    assert_eq!(
+        'é'.find_all("aébé").collect::<Vec<_>>(),
+        vec![(1, 3), (4, 6)]
+    );
+    assert_eq!(','.find_all("abc").count(), 0);
+}
+
+#[test]
+fn count_matches_naive() {
+    for haystack in ["a b c d e", "a b c d ", " ", "", "abc", "  a  "] {
+        let naive = StrSplit::new(haystack, " ").fold(0, |n, _| n + 1);
+        assert_eq!(StrSplit::new(haystack, " ").count(), naive, "{haystack:?}");
+    }
+
+    let mut split = StrSplit::new("a b c", ' ');
+    split.next();
+    assert_eq!(split.count(), 2);
+
+    let mut split = StrSplit::new("a", ' ');
+    split.next();
+    assert_eq!(split.count(), 0);
+
+    // An empty delimiter matches between every char, and `next` steps over each match.
+    for haystack in ["", "a", "abc", "é,"] {
+        for skip in 0..3 {
+            let mut split = StrSplit::new(haystack, "");
+            let mut naive = StrSplit::new(haystack, "");
+            for _ in 0..skip {
+                split.next();
+                naive.next();
+            }
+            let naive = naive.fold(0, |n, _| n + 1);
+            assert_eq!(split.count(), naive, "{haystack:?} after {skip}");
+        }
+    }
+}
+
+#[test]
+fn rsplitn_size_hint_and_fused() {
+    let mut split = rsplitn("a,b,c", 2, ',');
+    assert_eq!(split.size_hint(), (1, Some(2)));
+    assert_eq!(split.next(), Some("c"));
+    assert_eq!(split.size_hint(), (1, Some(1)));
+    assert_eq!(split.next(), Some("a,b"));
+    assert_eq!(split.size_hint(), (0, Some(0)));
+
+    assert_eq!(rsplitn("a", 0, ',').size_hint(), (0, Some(0)));
+    // Still allowed more pieces, but already out of input.
+    let mut short = rsplitn("a", 5, ',');
+    assert_eq!(short.next(), Some("a"));
+    assert_eq!(short.size_hint(), (0, Some(0)));
+    for _ in 0..3 {
+        assert_eq!(short.next(), None);
+    }
+
+    for haystack in SPLIT_SAMPLES {
+        for n in 0..5 {
+            let mut split = rsplitn(haystack, n, ',');
+            let mut left = haystack.rsplitn(n, ',').count();
+            loop {
+                let (lower, upper) = split.size_hint();
+                assert!(lower <= left && upper.is_none_or(|upper| left <= upper));
+                if split.next().is_none() {
+                    break;
+                }
+                left -= 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn find_all_empty_delimiter() {
+    let matches: Vec<_> = "".find_all("ab").collect();
+    assert_eq!(matches, [(0, 0), (1, 1), (2, 2)]);
+    let std_matches: Vec<_> = "ab".match_indices("").map(|(i, _)| (i, i)).collect();
+    assert_eq!(matches, std_matches);
+    // Steps over whole chars, not bytes.
+    assert_eq!("".find_all("é").collect::<Vec<_>>(), [(0, 0), (2, 2)]);
+    assert_eq!("".find_all("").collect::<Vec<_>>(), [(0, 0)]);
+    assert_eq!(AsciiCaseInsensitive("").find_all("xy").count(), 3);
+    assert_eq!(StrSplit::new("ab", "").count(), "ab".split("").count());
+    for haystack in ["", "a", "ab", "é,x"] {
+        let ours: Vec<_> = StrSplit::new(haystack, "").collect();
+        assert_eq!(ours, haystack.split("").collect::<Vec<_>>(), "{haystack:?}");
+        let ours: Vec<_> = StrSplit::builder(haystack, "").limit(2).build().collect();
+        assert_eq!(
+            ours,
+            haystack.splitn(2, "").collect::<Vec<_>>(),
+            "{haystack:?}"
+        );
+        let ours: Vec<_> = split_inclusive(haystack, "").collect();
+        assert_eq!(
+            ours,
+            haystack.split_inclusive("").collect::<Vec<_>>(),
+            "{haystack:?}"
+        );
+    }
+}
+
+#[test]
+fn rsplit_test() {
+    assert_eq!(rsplit("a.b.c", '.').collect::<Vec<_>>(), ["c", "b", "a"]);
+    assert_eq!(rsplit("file.tar.gz", '.').next(), Some("gz"));
+    assert_eq!(rsplit("a::b", "::").collect::<Vec<_>>(), ["b", "a"]);
+    assert_eq!(rsplit("a.b", '.').rev().collect::<Vec<_>>(), ["a", "b"]);
+    for haystack in SPLIT_SAMPLES {
+        let expected: Vec<_> = haystack.rsplit(',').collect();
+        assert_eq!(
+            rsplit(haystack, ',').collect::<Vec<_>>(),
+            expected,
+            "{haystack:?}"
+        );
+    }
+}
+
+#[test]
+fn splitn_test() {
+    assert_eq!(
+        splitn("a:b:c:d", 2, ':').collect::<Vec<_>>(),
+        ["a", "b:c:d"]
+    );
+    assert_eq!(splitn("a:b:c:d", 0, ':').next(), None);
+    assert_eq!(splitn("a:b:c:d", 1, ':').collect::<Vec<_>>(), ["a:b:c:d"]);
+    assert_eq!(splitn("a:b", 5, ':').collect::<Vec<_>>(), ["a", "b"]);
+    for haystack in SPLIT_SAMPLES {
+        for n in 0..5 {
+            assert_eq!(
+                splitn(haystack, n, ',').collect::<Vec<_>>(),
+                haystack.splitn(n, ',').collect::<Vec<_>>(),
+                "{haystack:?}, n = {n}"
+            );
+        }
+    }
+}
+
+#[test]
+fn char_predicate() {
+    assert_eq!(
+        split_all("a1b22c", CharPredicate(|c: char| c.is_numeric())),
+        vec!["a", "b", "", "c"]
+    );
+    assert_eq!(
+        split_all("x y\u{3000}z", CharPredicate(char::is_whitespace)),
+        vec!["x", "y", "z"]
+    );
+    let pieces: Vec<_> = StrSplit::new("a1b22c", CharPredicate(|c: char| c.is_numeric()))
+        .rev()
+        .collect();
+    assert_eq!(pieces, ["c", "", "b", "a"]);
+}
+
+#[test]
+fn split_inclusive_test() {
+    assert_eq!(
+        split_inclusive("a\nb\n", '\n').collect::<Vec<_>>(),
+        ["a\n", "b\n"]
+    );
+    assert_eq!(
+        split_inclusive("a\nb", '\n').collect::<Vec<_>>(),
+        ["a\n", "b"]
+    );
+    assert_eq!(split_inclusive("", '\n').next(), None);
+    assert_eq!(
+        split_inclusive("a,b,c", ',').rev().collect::<Vec<_>>(),
+        ["c", "b,", "a,"]
+    );
+    for haystack in SPLIT_SAMPLES {
+        assert_eq!(
+            split_inclusive(haystack, ',').collect::<Vec<_>>(),
+            haystack.split_inclusive(',').collect::<Vec<_>>(),
+            "{haystack:?}"
+        );
+    }
+}
+
+#[test]
+fn rsplitn_test() {
+    assert_eq!(
+        rsplitn("a.b.c", 2, '.').collect::<Vec<_>>(),
+        vec!["c", "a.b"]
+    );
+    for haystack in ["a.b.c", "a..b", ".a.", "", "abc"] {
+        for n in 0..5 {
+            assert_eq!(
+                rsplitn(haystack, n, '.').collect::<Vec<_>>(),
+                haystack.rsplitn(n, '.').collect::<Vec<_>>(),
+                "rsplitn({haystack:?}, {n})"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+const SPLIT_SAMPLES: [&str; 8] = [
+    "a,b,c",
+    "a,,b",
+    ",a,",
+    ",,",
+    "",
+    "abc",
+    "a,b,",
+    ",,a,,b,,c,,",
+];
+
+/// Drives `ours` and `theirs` with the same pattern of `next`/`next_back` calls (bit set = back).
+#[cfg(test)]
+fn assert_same_from_both_ends<'a>(
+    mut ours: impl DoubleEndedIterator<Item = &'a str>,
+    mut theirs: impl DoubleEndedIterator<Item = &'a str>,
+    order: u32,
+) {
+    for step in 0..12 {
+        if order & (1 << step) == 0 {
+            assert_eq!(ours.next(), theirs.next(), "next, order {order:b}");
+        } else {
+            assert_eq!(
+                ours.next_back(),
+                theirs.next_back(),
+                "next_back, order {order:b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn overlapping_substring_one_way_only() {
+    // Forwards and backwards, "aa" matches different parts of "aaa"; each direction on
+    // its own still agrees with std.
+    for haystack in ["aaa", "aaaaa", "baaab"] {
+        assert_eq!(
+            split_all(haystack, "aa"),
+            haystack.split("aa").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            rsplit(haystack, "aa").collect::<Vec<_>>(),
+            haystack.rsplit("aa").collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(split_all("aaa", "aa"), ["", "a"]);
+    assert_eq!(rsplit("aaa", "aa").collect::<Vec<_>>(), ["", "a"]);
+}
+
+#[test]
+fn mixed_ends_on_runs() {
+    for haystack in ["aaa", "aaaaa", "a", ""] {
+        for order in 0..1 << 12 {
+            assert_same_from_both_ends(StrSplit::new(haystack, 'a'), haystack.split('a'), order);
+        }
+    }
+    let mut split = StrSplit::new("ba,a,ab", ',');
+    assert_eq!(split.next(), Some("ba"));
+    assert_eq!(split.next_back(), Some("ab"));
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next_back(), None);
+}
+
+#[test]
+fn builder_defaults_to_plain_split() {
+    for haystack in SPLIT_SAMPLES {
+        for order in 0..1 << 12 {
+            assert_same_from_both_ends(
+                StrSplit::builder(haystack, ',').build(),
+                haystack.split(','),
+                order,
+            );
+        }
+    }
+}
+
+#[test]
+fn builder_limit() {
+    for haystack in SPLIT_SAMPLES {
+        for n in 0..6 {
+            assert_eq!(
+                StrSplit::builder(haystack, ',')
+                    .limit(n)
+                    .build()
+                    .collect::<Vec<_>>(),
+                haystack.splitn(n, ',').collect::<Vec<_>>(),
+                "{haystack:?} limit {n}"
+            );
+        }
+    }
+    let from_back: Vec<_> = StrSplit::builder("a,b,c", ',')
+        .limit(2)
+        .build()
+        .rev()
+        .collect();
+    assert_eq!(from_back, vec!["c", "a,b"]);
+}
+
+#[test]
+fn builder_inclusive() {
+    for haystack in SPLIT_SAMPLES {
+        for order in 0..1 << 12 {
+            assert_same_from_both_ends(
+                StrSplit::builder(haystack, ',').inclusive(true).build(),
+                haystack.split_inclusive(','),
+                order,
+            );
+        }
+    }
+}
+
+#[test]
+fn builder_skip_empty() {
+    for haystack in SPLIT_SAMPLES {
+        for order in 0..1 << 12 {
+            assert_same_from_both_ends(
+                StrSplit::builder(haystack, ',').skip_empty(true).build(),
+                haystack.split(',').filter(|piece| !piece.is_empty()),
+                order,
+            );
+        }
+    }
+}
+
+#[test]
+fn builder_limit_and_skip_empty() {
+    let split = |haystack| {
+        StrSplit::builder(haystack, ',')
+            .limit(3)
+            .skip_empty(true)
+            .build()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(split("a,,b,,c,d"), vec!["a", "b", "c,d"]);
+    assert_eq!(split(",,a,,,b,,,,c,,"), vec!["a", "b", "c,,"]);
+    assert_eq!(split("a,,b,,,"), vec!["a", "b"]);
+    assert_eq!(split(",,,"), Vec::<&str>::new());
+
+    let from_back: Vec<_> = StrSplit::builder("a,,b,,c,,", ',')
+        .limit(2)
+        .skip_empty(true)
+        .build()
+        .rev()
+        .collect();
+    assert_eq!(from_back, vec!["c", "a,,b"]);
+}
+
+#[test]
+fn builder_limit_and_inclusive() {
+    let pieces: Vec<_> = StrSplit::builder("a\nb\nc\n", '\n')
+        .limit(2)
+        .inclusive(true)
+        .build()
+        .collect();
+    assert_eq!(pieces, vec!["a\n", "b\nc\n"]);
+}
+
+#[test]
+fn skip_empty_consecutive() {
+    let pieces: Vec<_> = StrSplit::new("a,,b,", ',').skip_empty().collect();
+    assert_eq!(pieces, vec!["a", "b"]);
+    let pieces: Vec<_> = StrSplit::new("a,,,b,,,,c", ',').skip_empty().collect();
+    assert_eq!(pieces, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn skip_empty_leading_and_trailing() {
+    let pieces: Vec<_> = StrSplit::new(",,a, b,,", ',').skip_empty().collect();
+    assert_eq!(pieces, vec!["a", " b"]);
+    let pieces: Vec<_> = StrSplit::new("--a--b--", "--").skip_empty().collect();
+    assert_eq!(pieces, vec!["a", "b"]);
+    let pieces: Vec<_> = StrSplit::new(",,a,,", ',').skip_empty().rev().collect();
+    assert_eq!(pieces, vec!["a"]);
+}
+
+#[test]
+fn skip_empty_nothing_left() {
+    assert_eq!(StrSplit::new(",,,", ',').skip_empty().next(), None);
+    assert_eq!(StrSplit::new("", ',').skip_empty().next(), None);
+}
+
+#[test]
+fn indexed_offsets() {
+    let haystack = "ab:cd:e";
+    let pieces: Vec<_> = StrSplit::new(haystack, ':').indexed().collect();
+    assert_eq!(pieces, vec![(0, "ab"), (3, "cd"), (6, "e")]);
+    for (offset, piece) in pieces {
+        assert_eq!(&haystack[offset..offset + piece.len()], piece);
+    }
+}
+
+#[test]
+fn indexed_with_options() {
+    let pieces: Vec<_> = StrSplit::new("::ab::é:", ':')
+        .skip_empty()
+        .indexed()
+        .collect();
+    assert_eq!(pieces, vec![(2, "ab"), (6, "é")]);
+    let pieces: Vec<_> = StrSplit::new("ab:cd:e", ':').indexed().rev().collect();
+    assert_eq!(pieces, vec![(6, "e"), (3, "cd"), (0, "ab")]);
+    assert_eq!(StrSplit::new("", ':').indexed().next(), Some((0, "")));
+}
+
+#[test]
+fn trimmed_pieces() {
+    let pieces: Vec<_> = StrSplit::new("a , b , c", ',').trimmed().collect();
+    assert_eq!(pieces, vec!["a", "b", "c"]);
+    let pieces: Vec<_> = StrSplit::new(" a ,  , c ", ',').trimmed().rev().collect();
+    assert_eq!(pieces, vec!["c", "", "a"]);
+}
+
+#[test]
+fn trimmed_skip_empty() {
+    let pieces: Vec<_> = StrSplit::new(" a ,  ,, c ,", ',')
+        .trimmed()
+        .skip_empty()
+        .collect();
+    assert_eq!(pieces, vec!["a", "c"]);
+    let pieces: Vec<_> = StrSplit::new(" a ,  ,, c ,", ',')
+        .trimmed()
+        .skip_empty()
+        .rev()
+        .collect();
+    assert_eq!(pieces, vec!["c", "a"]);
+}
+
+#[test]
+fn every_n_chars() {
+    assert_eq!(split_all("abcdef", EveryNChars(2)), vec!["ab", "cd", "ef"]);
+    assert_eq!(split_all("abcde", EveryNChars(2)), vec!["ab", "cd", "e"]);
+    assert_eq!(split_all("ab", EveryNChars(5)), vec!["ab"]);
+    assert_eq!(split_all("", EveryNChars(3)), vec![""]);
+    // Chunks are counted in chars, so multi-byte chars stay whole.
+    assert_eq!(split_all("éaüb", EveryNChars(2)), vec!["éa", "üb"]);
+
+    let pieces: Vec<_> = StrSplit::new("abcde", EveryNChars(2)).rev().collect();
+    assert_eq!(pieces, vec!["e", "cd", "ab"]);
+    assert_eq!(StrSplit::new("abcdefg", EveryNChars(3)).count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "at least 1")]
+fn every_zero_chars_panics() {
+    StrSplit::new("abc", EveryNChars(0)).next();
+}
+
+#[test]
+fn closure_delimiter() {
+    let pieces: Vec<_> = StrSplit::new("a1b22c", |c: char| c.is_ascii_digit()).collect();
+    assert_eq!(pieces, vec!["a", "b", "", "c"]);
+    let pieces: Vec<_> = StrSplit::new("a1b22c", |c: char| c.is_ascii_digit())
+        .rev()
+        .collect();
+    assert_eq!(pieces, vec!["c", "", "b", "a"]);
+}
+
+#[cfg(test)]
+fn split_all<D: Delimiter>(haystack: &str, delimiter: D) -> Vec<&str> {
+    StrSplit::new(haystack, delimiter).collect()
+}
+
+#[test]
+fn every_delimiter_type() {
+    let expected = vec!["a", "b", "", "c"];
+    assert_eq!(split_all("a-b--c", '-'), expected);
+    assert_eq!(split_all("a-b--c", "-"), expected);
+    assert_eq!(split_all("a-b_-c", &['-', '_'][..]), expected);
+    assert_eq!(split_all("a-b_-c", ['-', '_']), expected);
+    assert_eq!(split_all("a-b--c", |c: char| c == '-'), expected);
+    assert_eq!(
+        split_all("a b  c", char::is_whitespace as fn(char) -> bool),
+        expected
+    );
+}
+
+#[test]
+fn char_range_delimiter() {
+    assert_eq!(split_all("a1b2c", '0'..='9'), vec!["a", "b", "c"]);
+    assert_eq!(split_all("x", '0'..='9'), vec!["x"]);
+    // 'é' and 'ü' are both two bytes in UTF-8.
+    assert_eq!(split_all("aébüc", 'à'..='ÿ'), vec!["a", "b", "c"]);
+    assert_eq!(('à'..='ÿ').find_last("aébüc"), Some((4, 6)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn string_delimiters() {
+    let sep = String::from("::");
+    assert_eq!(split_all("a::b::c", &sep), vec!["a", "b", "c"]);
+    assert_eq!(split_all("a::b::c", sep), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn char_set_from_the_back() {
+    let pieces: Vec<_> = StrSplit::new("a,b;c", [',', ';']).rev().collect();
+    assert_eq!(pieces, vec!["c", "b", "a"]);
+    assert_eq!(['x', 'y'].find_last("xabyc"), Some((3, 4)));
+}
+
+#[test]
+fn split_whitespace_test() {
+    let words: Vec<_> = split_whitespace("  a \t b \n c ").collect();
+    assert_eq!(words, vec!["a", "b", "c"]);
+    for haystack in ["", "   ", "one", " one  two\u{3000}three\r\n"] {
+        assert_eq!(
+            split_whitespace(haystack).collect::<Vec<_>>(),
+            haystack.split_whitespace().collect::<Vec<_>>(),
+            "{haystack:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn collect_into_reuses_buffer() {
+    let mut buf = Vec::new();
+    StrSplit::new("a,b,c", ',').collect_into(&mut buf);
+    assert_eq!(buf, ["a", "b", "c"]);
+    let capacity = buf.capacity();
+
+    buf.clear();
+    assert_eq!(StrSplit::new("x y", ' ').collect_into(&mut buf).len(), 2);
+    assert_eq!(buf, ["x", "y"]);
+    assert_eq!(buf.capacity(), capacity);
+
+    // Appends rather than replacing.
+    StrSplit::new("z", ' ').collect_into(&mut buf);
+    assert_eq!(buf, ["x", "y", "z"]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn join_test() {
+    assert_eq!(join([], ", "), "");
+    assert_eq!(join(["a"], ", "), "a");
+    assert_eq!(join(["a", "b", "c"], ", "), "a, b, c");
+    assert_eq!(join(["", ""], ","), ",");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn join_round_trip() {
+    for haystack in ["a b c", "a  b ", " ", "", "abc"] {
+        assert_eq!(join(StrSplit::new(haystack, " "), " "), haystack);
+    }
+    assert_eq!(
+        join(StrSplit::new("key=>value=>", "=>"), "=>"),
+        "key=>value=>"
+    );
+}
+
+#[test]
+fn count_with_options() {
+    let count = |builder: StrSplitBuilder<'static, char>| builder.build().count();
+    assert_eq!(count(StrSplit::builder("a,,b,", ',').skip_empty(true)), 2);
+    assert_eq!(count(StrSplit::builder("a,,b,", ',').inclusive(true)), 3);
+    assert_eq!(count(StrSplit::builder("a,,b,", ',').limit(2)), 2);
+}
+
+#[test]
+fn ascii_case_insensitive() {
+    let lower: Vec<_> = StrSplit::new("aXbxc", AsciiCaseInsensitive("x")).collect();
+    assert_eq!(lower, vec!["a", "b", "c"]);
+    let upper: Vec<_> = StrSplit::new("aXbxc", AsciiCaseInsensitive("X")).collect();
+    assert_eq!(upper, vec!["a", "b", "c"]);
+    let multi: Vec<_> =
+        StrSplit::new("one AND two and three", AsciiCaseInsensitive(" And ")).collect();
+    assert_eq!(multi, vec!["one", "two", "three"]);
+}
+
+#[test]
+fn ascii_case_insensitive_multibyte() {
+    // Non-ASCII characters around the match keep their byte offsets intact,
+    // and non-ASCII characters in the delimiter are matched exactly.
+    let pieces: Vec<_> = StrSplit::new("äSEPösepü", AsciiCaseInsensitive("sep")).collect();
+    assert_eq!(pieces, vec!["ä", "ö", "ü"]);
+    let pieces: Vec<_> = StrSplit::new("aÄbäc", AsciiCaseInsensitive("ä")).collect();
+    assert_eq!(pieces, vec!["aÄb", "c"]);
 }
 
 // Explanation of `str` vs `[char]`:
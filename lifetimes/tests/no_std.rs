@@ -0,0 +1,39 @@
+//! Uses the splitter from a `#![no_std]` crate so the public API stays `core`-only.
+//!
+//! Run with `cargo test -p lifetimes --no-default-features` to also build the library
+//! itself without `std`.
+#![no_std]
+
+use lifetimes::{Delimiter, StrSplit};
+
+#[test]
+fn str_delimiter() {
+    let mut split = StrSplit::new("a, b", ", ");
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn char_delimiter() {
+    let mut split = StrSplit::new("a b", ' ');
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.next(), None);
+}
+
+struct Dot;
+
+impl Delimiter for Dot {
+    fn find_next(&self, s: &str) -> Option<(usize, usize)> {
+        s.find('.').map(|start| (start, start + 1))
+    }
+}
+
+#[test]
+fn custom_delimiter() {
+    let mut split = StrSplit::new("a.b", Dot);
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.next(), None);
+}
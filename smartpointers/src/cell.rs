@@ -1,6 +1,46 @@
 // Explain that UnsafeCell gives a raw pointer.
 use std::cell::UnsafeCell;
 
+/// A mutable memory location that only ever hands out copies of its value, never
+/// references into it.
+///
+/// That is only sound on a single thread, so `Cell` is `!Sync`. Sharing one across threads
+/// is a compile error:
+///
+/// ```compile_fail,E0277
+/// use smartpointers::cell::Cell;
+///
+/// let cell = Cell::new(0);
+/// std::thread::scope(|s| {
+///     s.spawn(|| cell.set(1));
+///     s.spawn(|| cell.set(2));
+/// });
+/// ```
+///
+/// and so is a `&Cell` in anything that requires `Sync`:
+///
+/// ```compile_fail,E0277
+/// use smartpointers::cell::Cell;
+///
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<Cell<i32>>();
+/// ```
+///
+/// Moving the whole cell to another thread is fine, though, as long as the value is
+/// `Send`: then only that thread can reach it.
+///
+/// ```
+/// use smartpointers::cell::Cell;
+///
+/// let cell = Cell::new(0);
+/// let cell = std::thread::spawn(move || {
+///     cell.set(1);
+///     cell
+/// })
+/// .join()
+/// .unwrap();
+/// assert_eq!(cell.get(), 1);
+/// ```
 pub struct Cell<T> {
     value: UnsafeCell<T>,
 }
@@ -16,11 +56,19 @@ impl<T> Cell<T> {
     }
 
     pub fn set(&self, value: T) {
+        // Drop the old value only after it has left the cell: its `Drop` could call back
+        // into this cell, and must not see a half-overwritten value.
+        drop(self.replace(value));
+    }
+
+    /// Puts `value` in the cell and returns the old value.
+    ///
+    /// Unlike `get`, this doesn't need `T: Copy`, since the old value is moved out rather
+    /// than copied, so it works for cells holding a `Vec` or a `Box<[T]>`.
+    pub fn replace(&self, value: T) -> T {
         // SAFETY: we knoe no-one else is concurrently mutating self.value (because !Sync)
         // SAFETY: we knoe we are not invalidating any referneces, because we never give any out.
-        unsafe {
-            *self.value.get() = value;
-        }
+        unsafe { std::mem::replace(&mut *self.value.get(), value) }
     }
 
     pub fn get(&self) -> T
@@ -31,4 +79,111 @@ impl<T> Cell<T> {
         // and it is executing this function instead.
         unsafe { *self.value.get() }
     }
+
+    /// Replaces the value with one computed from it by `f`, and returns the old value.
+    ///
+    /// Being `!Sync` rules out other threads, but not `f` itself: it could capture this
+    /// same cell and call `set`, which would alias a `&mut` pointing into the cell. So the
+    /// value is moved out first, leaving `T::default()` behind while `f` runs, and `f`
+    /// only ever sees the moved-out value. That is why this needs `T: Default`.
+    pub fn replace_with<F: FnOnce(&mut T) -> T>(&self, f: F) -> T
+    where
+        T: Default,
+    {
+        let mut old = self.replace(T::default());
+        let new = f(&mut old);
+        // Anything `f` stored in the cell in the meantime is overwritten.
+        self.set(new);
+        old
+    }
+
+    /// Returns a raw pointer to the value, like `std::cell::Cell::as_ptr`.
+    ///
+    /// Creating the pointer is safe, but using it is up to the caller: reads and writes
+    /// through it must not overlap with a `set` or `get` on this cell, and it dangles once
+    /// the cell is dropped or moved.
+    pub fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+impl<T: PartialEq + Copy> PartialEq for Cell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Eq + Copy> Eq for Cell<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn send_when_value_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Cell<i32>>();
+        assert_send::<Cell<Vec<String>>>();
+    }
+
+    #[test]
+    fn eq_compares_values() {
+        assert!(Cell::new(1) == Cell::new(1));
+        assert!(Cell::new(1) != Cell::new(2));
+
+        let cell = Cell::new(1);
+        let other = Cell::new(2);
+        other.set(1);
+        assert!(cell == other);
+    }
+
+    #[test]
+    fn as_ptr_sees_set() {
+        let cell = Cell::new(1);
+        cell.set(2);
+        let ptr = cell.as_ptr();
+        // SAFETY: the cell is alive and nothing else touches it while we read.
+        assert_eq!(unsafe { *ptr }, 2);
+
+        // SAFETY: as above, and no reference into the cell exists.
+        unsafe { *ptr = 3 };
+        assert_eq!(cell.get(), 3);
+    }
+
+    #[test]
+    fn replace_with_computes_from_old() {
+        let cell = Cell::new(5);
+        assert_eq!(cell.replace_with(|&mut old| old * 2), 5);
+        assert_eq!(cell.get(), 10);
+
+        let cell = Cell::new(vec![1]);
+        let old = cell.replace_with(|v| {
+            v.push(2);
+            v.iter().map(|x| x * 10).collect()
+        });
+        assert_eq!(old, vec![1, 2]);
+        assert_eq!(cell.replace(Vec::new()), vec![10, 20]);
+    }
+
+    #[test]
+    fn replace_with_reentrant_set_is_overwritten() {
+        let cell = Cell::new(1);
+        let old = cell.replace_with(|&mut old| {
+            // The cell holds the default while `f` runs.
+            assert_eq!(cell.get(), 0);
+            cell.set(100);
+            old + 1
+        });
+        assert_eq!(old, 1);
+        assert_eq!(cell.get(), 2);
+    }
+
+    #[test]
+    fn replace_swaps_vecs() {
+        let cell = Cell::new(vec![1, 2]);
+        let mut other = vec![3];
+        other = cell.replace(other);
+        assert_eq!(other, vec![1, 2]);
+        assert_eq!(cell.replace(Vec::new()), vec![3]);
+    }
 }
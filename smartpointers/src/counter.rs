@@ -0,0 +1,61 @@
+use crate::cell::Cell;
+
+/// A counter that can be bumped through a shared reference.
+///
+/// This is the pattern from `cell_doc.rs`'s `Config` example packaged up: share it with
+/// `Rc<Counter>`, and every handle can increment the same count without needing `&mut`.
+pub struct Counter {
+    count: Cell<u64>,
+}
+
+impl Counter {
+    /// Creates a counter starting at zero.
+    pub fn new() -> Self {
+        Counter {
+            count: Cell::new(0),
+        }
+    }
+
+    /// Adds one to the count.
+    pub fn increment(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+
+    /// Returns the current count.
+    pub fn get(&self) -> u64 {
+        self.count.get()
+    }
+
+    /// Sets the count back to zero.
+    pub fn reset(&self) {
+        self.count.set(0);
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn shared_between_handles() {
+        let counter = Rc::new(Counter::new());
+        let a = Rc::clone(&counter);
+        let b = Rc::clone(&counter);
+
+        a.increment();
+        b.increment();
+        a.increment();
+        assert_eq!(counter.get(), 3);
+        assert_eq!(b.get(), 3);
+
+        b.reset();
+        assert_eq!(a.get(), 0);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::cell::Cell;
+
+/// Hands out unique, increasing IDs through a shared reference.
+///
+/// Like [`Counter`](crate::counter::Counter), the state is a `Cell<u64>`, so every handle
+/// of an `Rc<IdAllocator>` can allocate without `&mut`, and no two calls ever see the
+/// same ID.
+pub struct IdAllocator {
+    next: Cell<u64>,
+}
+
+impl IdAllocator {
+    /// Creates an allocator whose first ID is `0`.
+    pub fn new() -> Self {
+        IdAllocator { next: Cell::new(0) }
+    }
+
+    /// Returns a new ID, greater than every ID returned before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all `u64` IDs have been handed out, rather than wrapping around and
+    /// reusing one.
+    pub fn next_id(&self) -> u64 {
+        let id = self.next.get();
+        let next = id.checked_add(1).expect("IdAllocator ran out of IDs");
+        self.next.set(next);
+        id
+    }
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        IdAllocator::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::Rc;
+
+    #[test]
+    fn unique_and_ordered_across_handles() {
+        let allocator = Rc::new(IdAllocator::new());
+        let a = allocator.clone();
+        let b = allocator.clone();
+
+        let ids = [
+            a.next_id(),
+            b.next_id(),
+            a.next_id(),
+            b.next_id(),
+            a.next_id(),
+        ];
+        assert_eq!(ids, [0, 1, 2, 3, 4]);
+        assert_eq!(allocator.next_id(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "ran out of IDs")]
+    fn exhausted() {
+        let allocator = IdAllocator {
+            next: Cell::new(u64::MAX),
+        };
+        allocator.next_id();
+    }
+}
@@ -0,0 +1,90 @@
+use std::cell::UnsafeCell;
+
+/// A value that is computed by `F` the first time it is accessed.
+///
+/// `Cell<Option<T>>` would be enough to store the value, but `Cell` never hands out
+/// references, and `force` needs to return `&T`. So the value is kept in an `UnsafeCell`
+/// instead, and we rely on two things for safety:
+/// - `LazyCell` is `!Sync` (implied by `UnsafeCell`), so only one thread ever touches it.
+/// - once the value is set it is never written again, so every `&T` we hand out stays valid.
+pub struct LazyCell<T, F = fn() -> T> {
+    value: UnsafeCell<Option<T>>,
+    init: UnsafeCell<Option<F>>,
+}
+
+// implied by UnsafeCell<T>:
+// impl<T, F> !Sync for LazyCell<T, F> {};
+
+impl<T, F> LazyCell<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Creates a new `LazyCell` that will run `init` on first access.
+    pub fn new(init: F) -> Self {
+        LazyCell {
+            value: UnsafeCell::new(None),
+            init: UnsafeCell::new(Some(init)),
+        }
+    }
+
+    /// Returns the value, running the initializer first if this is the first access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializer tries to `force` this same cell, or if an earlier
+    /// initializer panicked.
+    pub fn force(&self) -> &T {
+        // SAFETY: no-one else is concurrently mutating self.value (because !Sync), and
+        // the only write happens below while it is still `None`, when no references exist.
+        if let Some(value) = unsafe { &*self.value.get() } {
+            return value;
+        }
+
+        // SAFETY: the `&mut` to `self.init` only lives for the `take`, so it has ended
+        // by the time `init` runs (and possibly calls back into `force`).
+        let init = unsafe { (*self.init.get()).take() }
+            .expect("LazyCell initializer re-entered the cell or panicked");
+        let value = init();
+
+        // SAFETY: `self.value` is still `None`: a re-entrant `force` would have found no
+        // initializer and panicked above. So no references to it have been handed out yet.
+        unsafe {
+            let slot = &mut *self.value.get();
+            slot.insert(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use std::rc::{Rc, Weak};
+
+    #[test]
+    fn initializes_once() {
+        let calls = Cell::new(0);
+        let lazy = LazyCell::new(|| {
+            calls.set(calls.get() + 1);
+            String::from("hello")
+        });
+        assert_eq!(calls.get(), 0);
+
+        assert_eq!(lazy.force(), "hello");
+        let first: &String = lazy.force();
+        let second: &String = lazy.force();
+        assert_eq!(calls.get(), 1);
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    #[should_panic(expected = "re-entered")]
+    fn reentrant_init_panics() {
+        type SelfForcing = LazyCell<i32, Box<dyn FnOnce() -> i32>>;
+        let lazy = Rc::new_cyclic(|weak: &Weak<SelfForcing>| {
+            let weak = weak.clone();
+            LazyCell::new(Box::new(move || *weak.upgrade().unwrap().force() + 1))
+        });
+        lazy.force();
+    }
+}
@@ -1,2 +1,9 @@
 pub mod cell;
+pub mod counter;
+pub mod id_allocator;
+pub mod lazycell;
+pub mod observable;
+pub mod rc;
 pub mod refcell;
+pub mod shared;
+pub mod token_bucket;
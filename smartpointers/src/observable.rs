@@ -0,0 +1,111 @@
+use crate::refcell::RefCell;
+
+type Callback<T> = Box<dyn FnMut(&T)>;
+
+/// A list of callbacks that are all called with each value passed to [`notify`].
+///
+/// The callbacks are `FnMut`, so calling them needs a mutable borrow of the list, and
+/// `notify` holds that borrow while they run. A callback that tries to `subscribe` (or
+/// `notify`) on the same `Observable` would need a second borrow; instead of panicking,
+/// that call does nothing and returns `false`.
+///
+/// [`notify`]: Observable::notify
+pub struct Observable<T> {
+    callbacks: RefCell<Vec<Callback<T>>>,
+}
+
+impl<T> Observable<T> {
+    /// Creates an `Observable` with no callbacks.
+    pub fn new() -> Self {
+        Observable {
+            callbacks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Adds `callback` to be called on every later `notify`.
+    ///
+    /// Returns `false`, without adding it, if called from inside one of this
+    /// `Observable`'s callbacks.
+    pub fn subscribe<F>(&self, callback: F) -> bool
+    where
+        F: FnMut(&T) + 'static,
+    {
+        self.callbacks
+            .with_mut(|callbacks| callbacks.push(Box::new(callback)))
+            .is_some()
+    }
+
+    /// Calls every callback with `value`, in the order they subscribed.
+    ///
+    /// Returns `false`, without calling anything, if called from inside one of this
+    /// `Observable`'s callbacks.
+    pub fn notify(&self, value: &T) -> bool {
+        self.callbacks
+            .with_mut(|callbacks| {
+                for callback in callbacks.iter_mut() {
+                    callback(value);
+                }
+            })
+            .is_some()
+    }
+}
+
+impl<T> Default for Observable<T> {
+    fn default() -> Self {
+        Observable::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::rc::Rc;
+
+    #[test]
+    fn notifies_in_order() {
+        let observable = Observable::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        for name in ["first", "second"] {
+            let log = log.clone();
+            assert!(observable.subscribe(move |value: &i32| {
+                log.borrow_mut().unwrap().push((name, *value));
+            }));
+        }
+
+        assert!(observable.notify(&1));
+        assert!(observable.notify(&2));
+        assert_eq!(
+            *log.borrow().unwrap(),
+            [("first", 1), ("second", 1), ("first", 2), ("second", 2)]
+        );
+    }
+
+    #[test]
+    fn reentrant_calls_are_refused() {
+        let observable = Rc::new(Observable::new());
+        let results = Rc::new(RefCell::new(Vec::new()));
+        {
+            // The callback keeps its own `Observable` alive (a leaked cycle), which is
+            // fine for a test.
+            let inner = observable.clone();
+            let results = results.clone();
+            observable.subscribe(move |_: &()| {
+                let subscribed = inner.subscribe(|_| {});
+                let notified = inner.notify(&());
+                results.borrow_mut().unwrap().push((subscribed, notified));
+            });
+        }
+
+        assert!(observable.notify(&()));
+        assert_eq!(*results.borrow().unwrap(), [(false, false)]);
+
+        // The refused subscription really wasn't added: the callback ran only once more.
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        assert!(observable.subscribe(move |_| counter.set(counter.get() + 1)));
+        assert!(observable.notify(&()));
+        assert_eq!(results.borrow().unwrap().len(), 2);
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -0,0 +1,478 @@
+use crate::cell::Cell;
+use std::alloc::{self, Layout};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr::{self, NonNull};
+
+/// The heap allocation shared by every `Rc` and `Weak` pointing at the same value.
+///
+/// For an unsized `T` like `[U]` or `str`, a pointer to this is a fat pointer: the address
+/// plus the slice length, which is what lets `size_of_val` find the end of `value`.
+/// `repr(C)` fixes the field order, so that `Rc::from_vec` can compute the same layout
+/// when it allocates one by hand.
+#[repr(C)]
+struct RcInner<T: ?Sized> {
+    strong: Cell<usize>,
+    /// The number of `Weak`s, plus one held jointly by all the `Rc`s while any exist.
+    weak: Cell<usize>,
+    /// Dropped by hand when the last `Rc` goes away, which may be before the allocation
+    /// itself is freed. An unsized field has to come last.
+    value: ManuallyDrop<T>,
+}
+
+/// A single-threaded reference-counted pointer.
+///
+/// Every clone points at the same heap allocation, and the value is dropped when the
+/// last clone is. The counts live in `Cell`s so that `clone` and `drop` can update them
+/// through the shared `&` that all the clones hold.
+///
+/// # Drop order
+///
+/// When the last `Rc` is dropped, the value is dropped, exactly once and right away.
+/// [`Weak`]s don't keep the value alive, only the allocation (so they can still read the
+/// counts); it is freed when the last `Rc` or `Weak`, whichever comes later, is dropped,
+/// and never before the value has been dropped.
+///
+/// # Unsized values
+///
+/// Like `std::rc::Rc`, the value can be a slice or a `str`, created with `From`:
+///
+/// ```
+/// use smartpointers::rc::Rc;
+///
+/// let name: Rc<str> = Rc::from("shared");
+/// let other = name.clone();
+/// assert_eq!(&*other, "shared");
+///
+/// let numbers: Rc<[i32]> = Rc::from(&[1, 2, 3][..]);
+/// assert_eq!(numbers.len(), 3);
+/// ```
+pub struct Rc<T: ?Sized> {
+    inner: NonNull<RcInner<T>>,
+    // Tells the drop checker that dropping an `Rc<T>` may drop a `T`.
+    _marker: PhantomData<RcInner<T>>,
+}
+
+// implied by NonNull:
+// impl<T> !Send for Rc<T> {};
+// impl<T> !Sync for Rc<T> {};
+
+impl<T> Rc<T> {
+    /// Moves `value` to the heap, with a reference count of one.
+    pub fn new(value: T) -> Self {
+        let inner = Box::new(RcInner {
+            value: ManuallyDrop::new(value),
+            strong: Cell::new(1),
+            weak: Cell::new(1),
+        });
+        Rc {
+            // SAFETY: Box does not give us a null pointer.
+            inner: unsafe { NonNull::new_unchecked(Box::into_raw(inner)) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a mutable reference to the value, cloning it first if it is shared.
+    ///
+    /// If `this` is the only `Rc` and there are no `Weak`s, the value is mutated in place.
+    /// Otherwise `this` is pointed at a fresh clone of the value (copy-on-write), so the
+    /// other `Rc`s keep seeing the original; a `Weak` can't be upgraded to the new one.
+    pub fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        if Rc::strong_count(this) != 1 || Rc::weak_count(this) != 0 {
+            *this = Rc::new(T::clone(this));
+        }
+        // SAFETY: there is only this Rc and no Weak that could upgrade to another one, so
+        // no other reference to the value exists, and `this` is borrowed mutably for as
+        // long as the returned reference.
+        unsafe { &mut this.inner.as_mut().value }
+    }
+}
+
+impl<T> Rc<[T]> {
+    /// Moves the elements of `vec` into a new `RcInner<[T]>`.
+    ///
+    /// The allocation can't come from a `Box<RcInner<[T]>>`, since the length isn't known
+    /// at compile time, so it is made by hand with the layout the compiler would give it.
+    fn from_vec(mut vec: Vec<T>) -> Self {
+        let len = vec.len();
+        let layout = Layout::new::<RcInner<()>>()
+            .extend(Layout::array::<T>(len).expect("Rc<[T]> too large"))
+            .expect("Rc<[T]> too large")
+            .0
+            .pad_to_align();
+        // SAFETY: the layout has the two counts in it, so it is never zero-sized.
+        let mem = unsafe { alloc::alloc(layout) };
+        if mem.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+        // Casting a `*mut [T]` keeps the length, which becomes the metadata of the
+        // `RcInner<[T]>` pointer.
+        let inner = ptr::slice_from_raw_parts_mut(mem.cast::<T>(), len) as *mut RcInner<[T]>;
+        // SAFETY: `inner` points at a fresh allocation laid out for an `RcInner<[T]>` of
+        // `len` elements. The elements are moved out of `vec`, which then only frees its
+        // buffer; nothing can panic in between and leave the allocation half-written.
+        unsafe {
+            ptr::addr_of_mut!((*inner).strong).write(Cell::new(1));
+            ptr::addr_of_mut!((*inner).weak).write(Cell::new(1));
+            let values = ptr::addr_of_mut!((*inner).value).cast::<T>();
+            ptr::copy_nonoverlapping(vec.as_ptr(), values, len);
+            vec.set_len(0);
+        }
+        Rc {
+            // SAFETY: checked for null above.
+            inner: unsafe { NonNull::new_unchecked(inner) },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Rc<T> {
+    /// Returns how many `Rc`s currently point at this value.
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().strong.get()
+    }
+
+    /// Returns how many `Weak`s currently point at this value.
+    pub fn weak_count(this: &Self) -> usize {
+        // Don't count the one shared by the `Rc`s.
+        this.inner().weak.get() - 1
+    }
+
+    /// Creates a [`Weak`] pointer to this value, which doesn't keep it alive.
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        let inner = this.inner();
+        inner.weak.set(inner.weak.get() + 1);
+        Weak { inner: this.inner }
+    }
+
+    fn inner(&self) -> &RcInner<T> {
+        // SAFETY: self.inner is a Box that is only deallocated when the last Rc and Weak
+        // go away. we have an Rc, therefore the Box has not been deallocated, so deref is fine.
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl<T> From<Vec<T>> for Rc<[T]> {
+    fn from(vec: Vec<T>) -> Self {
+        Rc::from_vec(vec)
+    }
+}
+
+impl<T: Clone> From<&[T]> for Rc<[T]> {
+    fn from(slice: &[T]) -> Self {
+        Rc::from_vec(slice.to_vec())
+    }
+}
+
+impl From<&str> for Rc<str> {
+    fn from(s: &str) -> Self {
+        let bytes = Rc::<[u8]>::from(s.as_bytes());
+        let inner = bytes.inner.as_ptr() as *mut RcInner<str>;
+        // Hand the count over to the new Rc.
+        std::mem::forget(bytes);
+        Rc {
+            // SAFETY: a non-null pointer to the same allocation, and `str` has the same
+            // layout as `[u8]`. The bytes were copied from a `&str`, so they are UTF-8.
+            inner: unsafe { NonNull::new_unchecked(inner) },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for Rc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // While an Rc exists, the value has not been dropped.
+        &self.inner().value
+    }
+}
+
+impl<T: ?Sized> Clone for Rc<T> {
+    fn clone(&self) -> Self {
+        let inner = self.inner();
+        inner.strong.set(inner.strong.get() + 1);
+        Rc {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for Rc<T> {
+    fn drop(&mut self) {
+        let inner = self.inner();
+        let count = inner.strong.get();
+        inner.strong.set(count - 1);
+        if count == 1 {
+            // SAFETY: we are the only Rc left, and we are being dropped.
+            // therefore, after us, there will be no Rc's, and no references to T.
+            // (Weak can only reach T through `upgrade`, which sees the count of 0.)
+            unsafe { ManuallyDrop::drop(&mut (*self.inner.as_ptr()).value) };
+            // Give up the Rcs' shared weak count; this frees the allocation if no Weak is left.
+            drop(Weak { inner: self.inner });
+        }
+        // otherwise there are other Rcs, so don't drop the value!
+    }
+}
+
+/// A pointer to an [`Rc`]'s value that doesn't keep it alive.
+///
+/// Created by [`Rc::downgrade`]. Use [`upgrade`](Weak::upgrade) to get an `Rc` back,
+/// which fails once every `Rc` has been dropped. Weak pointers are how cycles, like a child
+/// pointing back at its parent, avoid leaking.
+pub struct Weak<T: ?Sized> {
+    inner: NonNull<RcInner<T>>,
+}
+
+impl<T: ?Sized> Weak<T> {
+    /// Returns a new `Rc` to the value, or `None` if it has already been dropped.
+    pub fn upgrade(&self) -> Option<Rc<T>> {
+        match self.strong().get() {
+            0 => None,
+            count => {
+                self.strong().set(count + 1);
+                Some(Rc {
+                    inner: self.inner,
+                    _marker: PhantomData,
+                })
+            }
+        }
+    }
+
+    // The value may already be dropped, so unlike `Rc::inner` these only ever make
+    // references to the counts, never to the whole `RcInner`.
+    fn strong(&self) -> &Cell<usize> {
+        // SAFETY: the allocation is only freed once the weak count reaches 0, and this
+        // Weak is still counted.
+        unsafe { &(*self.inner.as_ptr()).strong }
+    }
+
+    fn weak(&self) -> &Cell<usize> {
+        // SAFETY: as in `strong`.
+        unsafe { &(*self.inner.as_ptr()).weak }
+    }
+}
+
+impl<T: ?Sized> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        self.weak().set(self.weak().get() + 1);
+        Weak { inner: self.inner }
+    }
+}
+
+impl<T: ?Sized> Drop for Weak<T> {
+    fn drop(&mut self) {
+        let count = self.weak().get();
+        self.weak().set(count - 1);
+        if count == 1 {
+            // SAFETY: no Rc (they share one weak count) and no other Weak is left, so no one
+            // can reach the allocation after us. The value was already dropped by the last
+            // Rc, and `ManuallyDrop` keeps the Box from dropping it again. For an unsized
+            // value, the Box frees it with the layout `Rc::from_vec` allocated it with.
+            drop(unsafe { Box::from_raw(self.inner.as_ptr()) });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refcell::RefCell;
+
+    #[test]
+    fn clones_share_the_value() {
+        let first = Rc::new(String::from("shared"));
+        let second = first.clone();
+        assert_eq!(Rc::strong_count(&first), 2);
+        assert!(std::ptr::eq(&*first, &*second));
+
+        drop(first);
+        assert_eq!(Rc::strong_count(&second), 1);
+        assert_eq!(*second, "shared");
+    }
+
+    #[test]
+    fn methods_through_deref() {
+        let rc = Rc::new(vec![1, 2, 3]);
+        assert_eq!(rc.len(), 3);
+        assert_eq!(rc.iter().sum::<i32>(), 6);
+        assert_eq!(rc.clone().first(), Some(&1));
+    }
+
+    #[test]
+    fn make_mut_unique_in_place() {
+        let mut rc = Rc::new(String::from("a"));
+        let before: *const String = &*rc;
+        Rc::make_mut(&mut rc).push('b');
+        assert_eq!(*rc, "ab");
+        assert!(std::ptr::eq(before, &*rc));
+    }
+
+    #[test]
+    fn make_mut_shared_clones() {
+        let mut rc = Rc::new(String::from("a"));
+        let other = rc.clone();
+        Rc::make_mut(&mut rc).push('b');
+        assert_eq!(*rc, "ab");
+        assert_eq!(*other, "a");
+        assert_eq!(Rc::strong_count(&rc), 1);
+        assert_eq!(Rc::strong_count(&other), 1);
+    }
+
+    /// Logs when it is dropped, to check when (and how often) `Rc` drops its value.
+    struct Logged<'a> {
+        name: &'static str,
+        log: &'a RefCell<Vec<&'static str>>,
+    }
+
+    impl Drop for Logged<'_> {
+        fn drop(&mut self) {
+            self.log.borrow_mut().unwrap().push(self.name);
+        }
+    }
+
+    #[test]
+    fn weak_does_not_keep_value_alive() {
+        let log = RefCell::new(Vec::new());
+        let rc = Rc::new(Logged {
+            name: "a",
+            log: &log,
+        });
+        let weak = Rc::downgrade(&rc);
+        let weak2 = weak.clone();
+        assert_eq!(Rc::weak_count(&rc), 2);
+
+        let upgraded = weak.upgrade().unwrap();
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(upgraded);
+        drop(rc);
+        // Dropped as soon as the last Rc went, even though Weaks remain.
+        assert_eq!(*log.borrow().unwrap(), ["a"]);
+        assert!(weak.upgrade().is_none());
+
+        drop(weak);
+        drop(weak2);
+        assert_eq!(*log.borrow().unwrap(), ["a"]);
+    }
+
+    #[test]
+    fn drop_orders() {
+        let log = RefCell::new(Vec::new());
+        let first = Rc::new(Logged {
+            name: "first",
+            log: &log,
+        });
+        let second = Rc::new(Logged {
+            name: "second",
+            log: &log,
+        });
+
+        // Weak dropped before the Rcs.
+        let first_clone = first.clone();
+        drop(Rc::downgrade(&first));
+        drop(first);
+        assert!(log.borrow().unwrap().is_empty());
+
+        // Weak outliving every Rc.
+        let weak_second = Rc::downgrade(&second);
+        drop(second);
+        assert_eq!(*log.borrow().unwrap(), ["second"]);
+        drop(first_clone);
+        assert_eq!(*log.borrow().unwrap(), ["second", "first"]);
+        assert!(weak_second.upgrade().is_none());
+        drop(weak_second);
+
+        // Each value was dropped exactly once.
+        assert_eq!(*log.borrow().unwrap(), ["second", "first"]);
+    }
+
+    #[test]
+    fn make_mut_detaches_weak() {
+        let mut rc = Rc::new(1);
+        let weak = Rc::downgrade(&rc);
+        *Rc::make_mut(&mut rc) += 1;
+        assert_eq!(*rc, 2);
+        // The Weak still points at the old value, which no Rc owns any more.
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn shared_str() {
+        let first: Rc<str> = Rc::from("héllo");
+        let second = first.clone();
+        assert_eq!(&*second, "héllo");
+        assert!(std::ptr::eq(&*first, &*second));
+        assert_eq!(Rc::strong_count(&first), 2);
+        drop(first);
+        assert_eq!(second.len(), 6);
+
+        let empty: Rc<str> = Rc::from("");
+        assert_eq!(&*empty.clone(), "");
+    }
+
+    #[test]
+    fn slice_elements_dropped_once() {
+        let log = RefCell::new(Vec::new());
+        let items = vec![
+            Logged {
+                name: "a",
+                log: &log,
+            },
+            Logged {
+                name: "b",
+                log: &log,
+            },
+        ];
+        let first: Rc<[Logged]> = Rc::from(items);
+        let second = first.clone();
+        let weak = Rc::downgrade(&second);
+        assert_eq!(second.len(), 2);
+
+        drop(first);
+        assert!(log.borrow().unwrap().is_empty());
+        drop(second);
+        assert_eq!(*log.borrow().unwrap(), ["a", "b"]);
+        assert!(weak.upgrade().is_none());
+        drop(weak);
+        assert_eq!(*log.borrow().unwrap(), ["a", "b"]);
+    }
+
+    #[test]
+    fn slice_from_borrowed() {
+        let strings = [String::from("x"), String::from("y")];
+        let rc: Rc<[String]> = Rc::from(&strings[..]);
+        assert_eq!(&*rc, &strings[..]);
+
+        // Elements with a larger alignment than the counts.
+        let wide: Rc<[u128]> = Rc::from(&[1, u128::MAX][..]);
+        assert_eq!(wide[1], u128::MAX);
+        assert_eq!(wide.as_ptr() as usize % std::mem::align_of::<u128>(), 0);
+
+        let zsts: Rc<[()]> = Rc::from(vec![(); 3]);
+        assert_eq!(zsts.len(), 3);
+        let empty: Rc<[i32]> = Rc::from(Vec::new());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn value_dropped_with_last_clone() {
+        struct SetOnDrop<'a>(&'a Cell<bool>);
+        impl Drop for SetOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let dropped = Cell::new(false);
+        let first = Rc::new(SetOnDrop(&dropped));
+        let second = first.clone();
+        drop(first);
+        assert!(!dropped.get());
+        drop(second);
+        assert!(dropped.get());
+    }
+}
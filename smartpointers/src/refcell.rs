@@ -1,15 +1,20 @@
 use crate::cell::Cell;
 use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
 
 /// Internal state representing the borrowing state of `RefCell`.
 /// - `Unshared`: No references currently borrowed.
 /// - `Shared(isize)`: Shared references are borrowed, with `isize` tracking count.
-/// - `Exclusive`: A mutable, exclusive reference is borrowed.
+/// - `Exclusive(isize)`: Mutable references are borrowed, with `isize` tracking count.
+///   There is only ever more than one after `RefMut::map_split`, and then each one points
+///   at a disjoint part of the value.
 #[derive(Clone, Copy)]
 enum RefState {
     Unshared,
     Shared(isize),
-    Exclusive,
+    Exclusive(isize),
 }
 
 /// `RefCell` provides interior mutability, allowing controlled mutable or shared access.
@@ -38,14 +43,14 @@ impl<T> RefCell<T> {
             RefState::Unshared => {
                 self.state.set(RefState::Shared(1));
                 // No mutable references exist; exclusive access would set state to `Exclusive`.
-                Some(Ref { refcell: self })
+                Some(Ref::new(self))
             }
             RefState::Shared(num) => {
                 self.state.set(RefState::Shared(num + 1));
                 // Only shared references exist; exclusive access would set state to `Exclusive`.
-                Some(Ref { refcell: self })
+                Some(Ref::new(self))
             }
-            RefState::Exclusive => None, // Exclusive reference exists; no shared access allowed.
+            RefState::Exclusive(_) => None, // Exclusive reference exists; no shared access allowed.
         }
     }
 
@@ -54,42 +59,191 @@ impl<T> RefCell<T> {
     pub fn borrow_mut(&self) -> Option<RefMut<'_, T>> {
         match self.state.get() {
             RefState::Unshared => {
-                self.state.set(RefState::Exclusive);
+                self.state.set(RefState::Exclusive(1));
                 // No other references exist; safe to allow exclusive access.
-                Some(RefMut { refcell: self })
+                Some(RefMut::new(self))
             }
             _ => None, // Shared or exclusive references exist; no mutable access allowed.
         }
     }
+
+    /// Runs `f` with a shared borrow of the value, releasing the borrow before returning.
+    ///
+    /// Returns `None` without calling `f` if the value is mutably borrowed. Other shared
+    /// borrows, including nested `with` calls, are fine.
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Option<R> {
+        let guard = self.borrow()?;
+        Some(f(&guard))
+    }
+
+    /// Runs `f` with a mutable borrow of the value, releasing the borrow before returning.
+    ///
+    /// Returns `None` without calling `f` if the value is already borrowed. Because the
+    /// guard never escapes, it can't be accidentally held across a later borrow.
+    pub fn with_mut<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> Option<R> {
+        let mut guard = self.borrow_mut()?;
+        Some(f(&mut guard))
+    }
+
+    /// Borrows the value without a guard, like the unstable `std` method of the same name.
+    ///
+    /// Returns `Err(BorrowError)` if the value is mutably borrowed. Unlike `borrow`, nothing records
+    /// that the returned reference exists, so the cell will still hand out a `RefMut`
+    /// while it is alive.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure no `borrow_mut` (or `with_mut`) on this cell happens for
+    /// as long as the returned reference is used; the cell can't check it.
+    pub unsafe fn try_borrow_unguarded(&self) -> Result<&T, BorrowError> {
+        match self.state.get() {
+            RefState::Exclusive(_) => Err(BorrowError),
+            // Safety: no `RefMut` exists now, and the caller promises none is created
+            // while the reference lives.
+            RefState::Unshared | RefState::Shared(_) => Ok(unsafe { &*self.value.get() }),
+        }
+    }
+
+    /// Returns `true` if any `Ref` or `RefMut` to the value is currently alive.
+    pub fn is_borrowed(&self) -> bool {
+        !matches!(self.state.get(), RefState::Unshared)
+    }
+
+    /// Returns `true` if a `RefMut` to the value is currently alive.
+    pub fn is_mutably_borrowed(&self) -> bool {
+        matches!(self.state.get(), RefState::Exclusive(_))
+    }
 }
 
+/// The error returned by [`RefCell::try_borrow_unguarded`] when the value is mutably
+/// borrowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorrowError;
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("already mutably borrowed")
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+/// Compares the values inside two `RefCell`s, by taking a shared borrow of each.
+///
+/// # Panics
+///
+/// Like `std::cell::RefCell`, panics if either cell is currently mutably borrowed, since
+/// there is no value that could honestly be compared (and returning `false` would claim
+/// a cell is unequal to itself).
+impl<T: PartialEq> PartialEq for RefCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let ours = self
+            .borrow()
+            .expect("RefCell compared while mutably borrowed");
+        let theirs = other
+            .borrow()
+            .expect("RefCell compared while mutably borrowed");
+        *ours == *theirs
+    }
+}
+
+impl<T: Eq> Eq for RefCell<T> {}
+
 /// A shared reference to the value inside a `RefCell`.
 /// Borrowed only when no mutable references exist.
-pub struct Ref<'refcell, T> {
-    refcell: &'refcell RefCell<T>,
+///
+/// The guard keeps a pointer to the value separately from the borrow state, so that
+/// [`Ref::map_split`] can hand out guards for parts of the value that still release the
+/// borrow of the whole cell.
+pub struct Ref<'refcell, T: ?Sized> {
+    value: NonNull<T>,
+    state: &'refcell Cell<RefState>,
+    _borrow: PhantomData<&'refcell T>,
+}
+
+impl<'refcell, T> Ref<'refcell, T> {
+    // The caller must already have recorded this borrow in `refcell.state`.
+    fn new(refcell: &'refcell RefCell<T>) -> Self {
+        Ref {
+            // Safety: `UnsafeCell::get` never returns null.
+            value: unsafe { NonNull::new_unchecked(refcell.value.get()) },
+            state: &refcell.state,
+            _borrow: PhantomData,
+        }
+    }
+}
+
+impl<'refcell, T: ?Sized> Ref<'refcell, T> {
+    /// Splits one shared borrow into two, for example to borrow two fields of a struct.
+    ///
+    /// This is an associated function (`Ref::map_split(guard, f)`) so it doesn't shadow a
+    /// method of `T`. The cell stays borrowed until both returned guards are dropped.
+    pub fn map_split<U: ?Sized, V: ?Sized, F>(
+        orig: Ref<'refcell, T>,
+        f: F,
+    ) -> (Ref<'refcell, U>, Ref<'refcell, V>)
+    where
+        F: FnOnce(&T) -> (&U, &V),
+    {
+        let state = orig.state;
+        let (u, v) = f(&orig);
+        let (u, v) = (NonNull::from(u), NonNull::from(v));
+        // `orig`'s share of the count is handed to the first half, and the second half
+        // registers one more.
+        std::mem::forget(orig);
+        match state.get() {
+            RefState::Shared(n) => state.set(RefState::Shared(n + 1)),
+            RefState::Exclusive(_) | RefState::Unshared => unreachable!(),
+        }
+        (
+            Ref {
+                value: u,
+                state,
+                _borrow: PhantomData,
+            },
+            Ref {
+                value: v,
+                state,
+                _borrow: PhantomData,
+            },
+        )
+    }
 }
 
-impl<T> std::ops::Deref for Ref<'_, T> {
+impl<T: ?Sized> std::ops::Deref for Ref<'_, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
         // Safety: `Ref` is only created if no exclusive references exist.
         // State is set to `Shared`, preventing future exclusive borrows.
-        unsafe { &*self.refcell.value.get() }
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Ref<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Forwards to the borrowed value through `Deref`.
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for Ref<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
     }
 }
 
-impl<T> Drop for Ref<'_, T> {
+impl<T: ?Sized> Drop for Ref<'_, T> {
     fn drop(&mut self) {
-        match self.refcell.state.get() {
-            RefState::Exclusive | RefState::Unshared => {
+        match self.state.get() {
+            RefState::Exclusive(_) | RefState::Unshared => {
                 unreachable!() // Invalid state; `Ref` would not exist if these were set.
             }
             RefState::Shared(1) => {
-                self.refcell.state.set(RefState::Unshared); // Last shared reference dropped.
+                self.state.set(RefState::Unshared); // Last shared reference dropped.
             }
             RefState::Shared(n) => {
-                self.refcell.state.set(RefState::Shared(n - 1)); // Decrement shared count.
+                self.state.set(RefState::Shared(n - 1)); // Decrement shared count.
             }
         }
     }
@@ -97,36 +251,301 @@ impl<T> Drop for Ref<'_, T> {
 
 /// An exclusive, mutable reference to the value inside a `RefCell`.
 /// Borrowed only when no other references exist.
-pub struct RefMut<'refcell, T> {
-    refcell: &'refcell RefCell<T>,
+pub struct RefMut<'refcell, T: ?Sized> {
+    value: NonNull<T>,
+    state: &'refcell Cell<RefState>,
+    _borrow: PhantomData<&'refcell mut T>,
 }
 
-impl<T> std::ops::Deref for RefMut<'_, T> {
+impl<'refcell, T> RefMut<'refcell, T> {
+    // The caller must already have recorded this borrow in `refcell.state`.
+    fn new(refcell: &'refcell RefCell<T>) -> Self {
+        RefMut {
+            // Safety: `UnsafeCell::get` never returns null.
+            value: unsafe { NonNull::new_unchecked(refcell.value.get()) },
+            state: &refcell.state,
+            _borrow: PhantomData,
+        }
+    }
+}
+
+impl<'refcell, T: ?Sized> RefMut<'refcell, T> {
+    /// Consumes the guard without releasing the borrow, returning a `&mut T` that lives
+    /// as long as the `RefCell` itself.
+    ///
+    /// This is an associated function (`RefMut::leak(guard)`) so it doesn't shadow a
+    /// method of `T`. The leak is permanent: the cell stays `Exclusive` forever, so every
+    /// later `borrow` and `borrow_mut` returns `None`.
+    pub fn leak(orig: RefMut<'refcell, T>) -> &'refcell mut T {
+        let mut value = orig.value;
+        // Skip `Drop`, which is what would have reset the state to `Unshared`.
+        std::mem::forget(orig);
+        // Safety: the state stays `Exclusive`, so no other reference to the value can
+        // ever be created while the returned one is alive.
+        unsafe { value.as_mut() }
+    }
+
+    /// Splits one mutable borrow into two, for disjoint parts of the value.
+    ///
+    /// Like [`Ref::map_split`], the cell stays borrowed until both halves are dropped.
+    /// The borrow checker makes `f` prove the two parts don't overlap.
+    pub fn map_split<U: ?Sized, V: ?Sized, F>(
+        mut orig: RefMut<'refcell, T>,
+        f: F,
+    ) -> (RefMut<'refcell, U>, RefMut<'refcell, V>)
+    where
+        F: FnOnce(&mut T) -> (&mut U, &mut V),
+    {
+        let state = orig.state;
+        let (u, v) = f(&mut orig);
+        let (u, v) = (NonNull::from(u), NonNull::from(v));
+        std::mem::forget(orig);
+        match state.get() {
+            RefState::Exclusive(n) => state.set(RefState::Exclusive(n + 1)),
+            RefState::Shared(_) | RefState::Unshared => unreachable!(),
+        }
+        (
+            RefMut {
+                value: u,
+                state,
+                _borrow: PhantomData,
+            },
+            RefMut {
+                value: v,
+                state,
+                _borrow: PhantomData,
+            },
+        )
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for RefMut<'_, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
         // Safety: Refer to the `DerefMut` implementation for reasoning.
-        unsafe { &*self.refcell.value.get() }
+        unsafe { self.value.as_ref() }
     }
 }
 
-impl<T> std::ops::DerefMut for RefMut<'_, T> {
+impl<T: ?Sized> std::ops::DerefMut for RefMut<'_, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // Safety: `RefMut` is only created if no other references exist.
         // State is set to `Exclusive`, preventing any future borrows.
-        unsafe { &mut *self.refcell.value.get() }
+        unsafe { self.value.as_mut() }
     }
 }
 
-impl<T> Drop for RefMut<'_, T> {
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Forwards to the borrowed value through `Deref`.
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for RefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for RefMut<'_, T> {
     fn drop(&mut self) {
-        match self.refcell.state.get() {
+        match self.state.get() {
             RefState::Shared(_) | RefState::Unshared => {
                 unreachable!() // Invalid state; `RefMut` would not exist if these were set.
             }
-            RefState::Exclusive => {
-                self.refcell.state.set(RefState::Unshared); // Exclusive access ended.
+            RefState::Exclusive(1) => {
+                self.state.set(RefState::Unshared); // Exclusive access ended.
+            }
+            RefState::Exclusive(n) => {
+                self.state.set(RefState::Exclusive(n - 1)); // Another split half is still alive.
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrow_flags_follow_ref() {
+        let cell = RefCell::new(5);
+        assert!(!cell.is_borrowed());
+
+        let first = cell.borrow().unwrap();
+        let second = cell.borrow().unwrap();
+        assert!(cell.is_borrowed());
+        assert!(!cell.is_mutably_borrowed());
+
+        drop(first);
+        assert!(cell.is_borrowed());
+        drop(second);
+        assert!(!cell.is_borrowed());
+    }
+
+    #[test]
+    fn borrow_flags_follow_ref_mut() {
+        let cell = RefCell::new(5);
+        let guard = cell.borrow_mut().unwrap();
+        assert!(cell.is_borrowed());
+        assert!(cell.is_mutably_borrowed());
+
+        drop(guard);
+        assert!(!cell.is_borrowed());
+        assert!(!cell.is_mutably_borrowed());
+    }
+
+    #[test]
+    fn leaked_ref_mut_keeps_cell_borrowed() {
+        let cell = RefCell::new(vec![1]);
+        let leaked: &mut Vec<i32> = RefMut::leak(cell.borrow_mut().unwrap());
+        leaked.push(2);
+        assert_eq!(leaked, &[1, 2]);
+
+        assert!(cell.is_mutably_borrowed());
+        assert!(cell.borrow().is_none());
+        assert!(cell.borrow_mut().is_none());
+    }
+
+    #[test]
+    fn map_split_ref_releases_after_both_halves() {
+        let cell = RefCell::new((1, 2));
+        let (first, second) = Ref::map_split(cell.borrow().unwrap(), |pair| (&pair.0, &pair.1));
+        assert_eq!((*first, *second), (1, 2));
+        assert!(cell.borrow_mut().is_none());
+
+        drop(first);
+        assert!(cell.is_borrowed());
+        assert!(cell.borrow_mut().is_none());
+        drop(second);
+        assert!(!cell.is_borrowed());
+        assert!(cell.borrow_mut().is_some());
+    }
+
+    #[test]
+    fn map_split_ref_mut_writes_both_halves() {
+        let cell = RefCell::new([1, 2, 3, 4]);
+        let (mut left, mut right) =
+            RefMut::map_split(cell.borrow_mut().unwrap(), |arr| arr.split_at_mut(2));
+        left[0] = 10;
+        right[1] = 40;
+
+        drop(right);
+        assert!(cell.is_mutably_borrowed());
+        assert!(cell.borrow().is_none());
+        drop(left);
+        assert!(!cell.is_borrowed());
+        assert_eq!(*cell.borrow().unwrap(), [10, 2, 3, 40]);
+    }
+
+    #[test]
+    fn nested_with_shares_the_borrow() {
+        let cell = RefCell::new(3);
+        let sum = cell.with(|outer| cell.with(|inner| outer + inner));
+        assert_eq!(sum, Some(Some(6)));
+        assert!(!cell.is_borrowed());
+    }
+
+    #[test]
+    fn with_mut_inside_with_fails() {
+        let cell = RefCell::new(3);
+        assert_eq!(cell.with(|_| cell.with_mut(|v| *v += 1)), Some(None));
+        assert_eq!(cell.with(|v| *v), Some(3));
+
+        let guard = cell.borrow_mut().unwrap();
+        assert_eq!(cell.with(|v| *v), None);
+        drop(guard);
+    }
+
+    #[test]
+    fn try_borrow_unguarded_is_untracked() {
+        let cell = RefCell::new(String::from("hi"));
+        let guard = cell.borrow().unwrap();
+        // SAFETY: nothing mutably borrows `cell` while `value` is used.
+        let value = unsafe { cell.try_borrow_unguarded() }.unwrap();
+        assert_eq!(value, "hi");
+        assert_eq!(*guard, "hi");
+        drop(guard);
+        // The unguarded reference doesn't count as a borrow.
+        assert!(!cell.is_borrowed());
+        assert_eq!(value.len(), 2);
+    }
+
+    #[test]
+    fn try_borrow_unguarded_while_mutably_borrowed() {
+        let cell = RefCell::new(1);
+        let guard = cell.borrow_mut().unwrap();
+        // SAFETY: the call fails, so no reference is returned.
+        assert_eq!(unsafe { cell.try_borrow_unguarded() }, Err(BorrowError));
+        drop(guard);
+        // SAFETY: nothing mutably borrows `cell` while the reference is used.
+        assert_eq!(unsafe { cell.try_borrow_unguarded() }, Ok(&1));
+    }
+
+    #[test]
+    fn with_mut_releases_borrow() {
+        let cell = RefCell::new(vec![1]);
+        assert_eq!(
+            cell.with_mut(|v| {
+                v.push(2);
+                v.len()
+            }),
+            Some(2)
+        );
+        assert!(!cell.is_borrowed());
+        assert_eq!(*cell.borrow().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn with_mut_while_borrowed_is_none() {
+        let cell = RefCell::new(0);
+        let guard = cell.borrow().unwrap();
+        let mut called = false;
+        assert_eq!(cell.with_mut(|_| called = true), None);
+        assert!(!called);
+        drop(guard);
+
+        // Borrowing the same cell again from inside `f` also fails instead of panicking.
+        assert_eq!(cell.with_mut(|_| cell.with_mut(|_| ())), Some(None));
+    }
+
+    #[test]
+    fn eq_compares_contents() {
+        assert!(RefCell::new(vec![1, 2]) == RefCell::new(vec![1, 2]));
+        assert!(RefCell::new(vec![1, 2]) != RefCell::new(vec![1]));
+
+        // Shared borrows don't get in the way, and a cell equals itself.
+        let cell = RefCell::new(String::from("a"));
+        let _guard = cell.borrow().unwrap();
+        assert!(cell == cell);
+    }
+
+    #[test]
+    #[should_panic(expected = "mutably borrowed")]
+    fn eq_while_mutably_borrowed_panics() {
+        let cell = RefCell::new(1);
+        let other = RefCell::new(1);
+        let _guard = other.borrow_mut().unwrap();
+        let _ = cell == other;
+    }
+
+    #[test]
+    fn format_ref() {
+        let cell = RefCell::new(42);
+        let guard = cell.borrow().unwrap();
+        assert_eq!(format!("{:?}", guard), "42");
+        assert_eq!(format!("{}", guard), "42");
+        assert_eq!(format!("{:>4}", guard), "  42");
+    }
+
+    #[test]
+    fn format_ref_mut() {
+        let cell = RefCell::new(String::from("hi"));
+        let mut guard = cell.borrow_mut().unwrap();
+        guard.push('!');
+        assert_eq!(format!("{:?}", guard), "\"hi!\"");
+        assert_eq!(format!("{}", guard), "hi!");
+    }
+}
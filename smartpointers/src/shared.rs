@@ -0,0 +1,62 @@
+use crate::rc::Rc;
+use crate::refcell::{Ref, RefCell, RefMut};
+
+/// A handle to a value that several owners can both read and mutate: the
+/// `Rc<RefCell<T>>` pattern, packaged up.
+///
+/// `Rc` provides the shared ownership and `RefCell` the runtime-checked mutation, so
+/// borrowing follows the same rules as `RefCell`: any number of `borrow`s, or a single
+/// `borrow_mut`, across all handles at once.
+pub struct Shared<T> {
+    inner: Rc<RefCell<T>>,
+}
+
+impl<T> Shared<T> {
+    /// Creates a handle to a new shared value.
+    pub fn new(value: T) -> Self {
+        Shared {
+            inner: Rc::new(RefCell::new(value)),
+        }
+    }
+
+    /// Borrows the value, returning `None` if any handle has it mutably borrowed.
+    pub fn borrow(&self) -> Option<Ref<'_, T>> {
+        self.inner.borrow()
+    }
+
+    /// Mutably borrows the value, returning `None` if any handle has it borrowed.
+    pub fn borrow_mut(&self) -> Option<RefMut<'_, T>> {
+        self.inner.borrow_mut()
+    }
+
+    /// Returns another handle to the same value (not a copy of the value).
+    pub fn clone_handle(&self) -> Self {
+        Shared {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation_visible_through_clone() {
+        let shared = Shared::new(vec![1]);
+        let handle = shared.clone_handle();
+        shared.borrow_mut().unwrap().push(2);
+        assert_eq!(*handle.borrow().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn borrow_rules_span_handles() {
+        let shared = Shared::new(0);
+        let handle = shared.clone_handle();
+        let guard = shared.borrow_mut().unwrap();
+        assert!(handle.borrow().is_none());
+        assert!(handle.borrow_mut().is_none());
+        drop(guard);
+        assert!(handle.borrow_mut().is_some());
+    }
+}
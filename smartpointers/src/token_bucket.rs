@@ -0,0 +1,151 @@
+use crate::cell::Cell;
+use std::time::Instant;
+
+/// A source of the current time, so that a [`TokenBucket`] can be driven by a fake clock in
+/// tests instead of waiting for real time to pass.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The real clock, `Instant::now`.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+/// A single-threaded rate limiter that allows bursts of up to `capacity` and refills at a
+/// steady `rate` of tokens per second.
+///
+/// The token count and the time of the last refill are in `Cell`s, so callers only need a
+/// `&TokenBucket` (or an `Rc` of one) to spend tokens. Refilling is lazy: instead of a
+/// timer, each `try_acquire` first adds whatever has accumulated since the last call.
+pub struct TokenBucket<C = SystemClock> {
+    capacity: f64,
+    rate: f64,
+    tokens: Cell<f64>,
+    last_refill: Cell<Instant>,
+    clock: C,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket that refills `rate` tokens per second, up to `capacity`.
+    pub fn new(capacity: f64, rate: f64) -> Self {
+        TokenBucket::with_clock(capacity, rate, SystemClock)
+    }
+}
+
+impl<C: Clock> TokenBucket<C> {
+    /// Like [`new`](TokenBucket::new), but reading the time from `clock`.
+    pub fn with_clock(capacity: f64, rate: f64, clock: C) -> Self {
+        TokenBucket {
+            capacity,
+            rate,
+            tokens: Cell::new(capacity),
+            last_refill: Cell::new(clock.now()),
+            clock,
+        }
+    }
+
+    /// Takes `n` tokens if that many are available, returning whether it did.
+    ///
+    /// On `false` nothing is taken, so a smaller request can still succeed.
+    pub fn try_acquire(&self, n: f64) -> bool {
+        self.refill();
+        let tokens = self.tokens.get();
+        if tokens >= n {
+            self.tokens.set(tokens - n);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refill(&self) {
+        let now = self.clock.now();
+        // A clock that went backwards just adds nothing.
+        let elapsed = now.saturating_duration_since(self.last_refill.get());
+        let refilled = self.tokens.get() + elapsed.as_secs_f64() * self.rate;
+        self.tokens.set(refilled.min(self.capacity));
+        self.last_refill.set(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// A clock that only moves when told to.
+    struct FakeClock {
+        now: Cell<Instant>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            FakeClock {
+                now: Cell::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn depletes_then_refills() {
+        let clock = FakeClock::new();
+        let bucket = TokenBucket::with_clock(3.0, 2.0, &clock);
+
+        assert!(bucket.try_acquire(2.0));
+        assert!(bucket.try_acquire(1.0));
+        assert!(!bucket.try_acquire(1.0));
+
+        // Half a second at 2 tokens per second.
+        clock.advance(Duration::from_millis(500));
+        assert!(bucket.try_acquire(1.0));
+        assert!(!bucket.try_acquire(1.0));
+    }
+
+    #[test]
+    fn refill_is_capped() {
+        let clock = FakeClock::new();
+        let bucket = TokenBucket::with_clock(2.0, 1.0, &clock);
+        assert!(bucket.try_acquire(2.0));
+
+        clock.advance(Duration::from_secs(60));
+        assert!(!bucket.try_acquire(3.0));
+        assert!(bucket.try_acquire(2.0));
+        assert!(!bucket.try_acquire(0.5));
+    }
+
+    #[test]
+    fn failed_acquire_takes_nothing() {
+        let clock = FakeClock::new();
+        let bucket = TokenBucket::with_clock(1.0, 1.0, &clock);
+        assert!(!bucket.try_acquire(1.5));
+        assert!(bucket.try_acquire(1.0));
+    }
+
+    #[test]
+    fn system_clock() {
+        let bucket = TokenBucket::new(1.0, 0.0);
+        assert!(bucket.try_acquire(1.0));
+        assert!(!bucket.try_acquire(1.0));
+    }
+}